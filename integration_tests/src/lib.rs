@@ -0,0 +1,89 @@
+//! Harness for running the Ethos contracts against each other in native tests.
+//!
+//! Each contract is deployed into its own `TestVM` and driven only through its ABI
+//! router, the same way a transaction would reach it on-chain. When one contract calls
+//! another, the exact calldata is routed through the callee's real router and the result
+//! is mocked on the caller's VM. A selector or encoding mismatch between crates then shows
+//! up as a failed call rather than being papered over by a hand-written mock.
+
+use core::borrow::BorrowMut;
+use core::marker::PhantomData;
+
+use alloy_sol_types::SolCall;
+use stylus_sdk::{
+    abi::{router_entrypoint, Router, CONSTRUCTOR_SELECTOR},
+    alloy_primitives::Address,
+    host::VM,
+    prelude::*,
+    testing::TestVM,
+    ArbResult,
+};
+
+/// A contract of type `C` deployed at `address` in its own VM
+pub struct Deployed<C> {
+    pub vm: TestVM,
+    pub address: Address,
+    contract: PhantomData<C>,
+}
+
+impl<C> Deployed<C>
+where
+    C: Router<C>
+        + StorageType
+        + TopLevelStorage
+        + BorrowMut<<C as Router<C>>::Storage>
+        + ValueDenier,
+{
+    /// Run the constructor with ABI-encoded `args`, deployed by `deployer`
+    pub fn deploy(address: Address, deployer: Address, args: Vec<u8>) -> Result<Self, Vec<u8>> {
+        let vm = TestVM::default();
+        vm.set_contract_address(address);
+
+        let deployed = Self {
+            vm,
+            address,
+            contract: PhantomData,
+        };
+        let mut calldata = CONSTRUCTOR_SELECTOR.to_be_bytes().to_vec();
+        calldata.extend(args);
+        deployed.send(deployer, calldata)?;
+
+        Ok(deployed)
+    }
+
+    /// Send raw calldata from `sender` through the contract's router
+    pub fn send(&self, sender: Address, calldata: Vec<u8>) -> ArbResult {
+        self.vm.set_sender(sender);
+        self.vm.set_tx_origin(sender);
+        router_entrypoint::<C, C>(
+            calldata,
+            VM {
+                host: Box::new(self.vm.clone()),
+            },
+        )
+    }
+
+    /// Send a typed call from `sender` and decode what it returns
+    pub fn call<T: SolCall>(&self, sender: Address, call: T) -> Result<T::Return, Vec<u8>> {
+        let returned = self.send(sender, call.abi_encode())?;
+        T::abi_decode_returns(&returned, true).map_err(|_| returned)
+    }
+
+    /// Answer `call` when `caller` makes it, with what this contract returns right now.
+    /// Answers are fixed when served, so serve again after this contract's state changes.
+    pub fn serve<D, T: SolCall>(&self, caller: &Deployed<D>, call: T) {
+        let calldata = call.abi_encode();
+        let result = self.send(caller.address, calldata.clone());
+        caller.vm.mock_static_call(self.address, calldata, result);
+    }
+
+    /// Let `caller` make the state-changing `call`. It runs through this contract's router
+    /// as soon as it is served, and `caller` sees that outcome when it makes the call.
+    /// `TestVM` answers an unserved call with empty success, which a caller expecting no
+    /// return data cannot tell apart, so build `call` from the caller's own interface type.
+    pub fn serve_call<D, T: SolCall>(&self, caller: &Deployed<D>, call: T) {
+        let calldata = call.abi_encode();
+        let result = self.send(caller.address, calldata.clone());
+        caller.vm.mock_call(self.address, calldata, result);
+    }
+}
@@ -0,0 +1,445 @@
+use alloy_primitives::{address, Address, U256};
+use alloy_sol_types::{sol, SolCall, SolError, SolEvent, SolValue};
+use integration_tests::Deployed;
+use issuer_registry::IssuerRegistry;
+use reputation_aggregator::ReputationAggregator;
+use reputation_gate::ReputationGate;
+use sbt::{IssuerUpdated, SBT};
+use sbt_factory::{IEthosSBT, SBTFactory};
+
+// The contracts' ABIs as an outside caller sees them
+sol! {
+    interface IIssuerRegistry {
+        function registerAsIssuer() external;
+        function setReputationRequirement(address reputation_staking, uint256 min_reputation) external;
+        function isIssuer(address issuer_address) external view returns (bool);
+    }
+
+    interface ISBT {
+        function mintToMany(address[] recipients) external returns (uint256[]);
+        function burn(uint256 token_id) external;
+        function initialize(address issuer) external;
+        function getIssuer() external view returns (address);
+        function factorySetIssuer(address new_issuer) external;
+        function totalSupply() external view returns (uint256);
+        function tokenURI(uint256 token_id) external view returns (string);
+    }
+
+    interface ISBTFactory {
+        function registerSbtCollection(address sbt_address, string name, string symbol) external;
+        function initializeCollection(address sbt_address, address issuer) external;
+        function recoverCollectionIssuer(address sbt_address, address new_issuer) external;
+        function authorizeMinter(address sbt_address, address minter) external;
+        function isAuthorizedMinter(address sbt_address, address minter) external view returns (bool);
+        function collectionSupply(address sbt_address) external view returns (uint256);
+        function issuerTotalMinted(address issuer) external view returns (uint256);
+        function setDefaultBaseUri(string base_uri) external;
+        function defaultBaseUri() external view returns (string);
+    }
+
+    interface IReputationAggregator {
+        function addPool(address pool) external;
+        function totalReputationOf(address account) external view returns (uint256);
+    }
+
+    interface IReputationGate {
+        function setTiers(uint256[] thresholds) external;
+        function setRequiredTier(uint256 tier) external;
+        function checkAccess(address account) external view returns (bool);
+        function tierOf(address account) external view returns (uint256);
+    }
+
+    interface IReputationStaking {
+        function reputationOf(address account) external view returns (uint256);
+    }
+
+    error InsufficientReputation(uint256 provided, uint256 required);
+}
+
+const OWNER: Address = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+const ISSUER: Address = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+const ALICE: Address = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+const BOB: Address = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+const CAROL: Address = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+
+const REGISTRY_ADDRESS: Address = Address::with_last_byte(0x10);
+const FACTORY_ADDRESS: Address = Address::with_last_byte(0x11);
+const SBT_ADDRESS: Address = Address::with_last_byte(0x12);
+const AGGREGATOR_ADDRESS: Address = Address::with_last_byte(0x13);
+const GATE_ADDRESS: Address = Address::with_last_byte(0x14);
+// No staking contract exists yet; its answers are mocked from the shared interface
+const STAKING_ADDRESS: Address = Address::with_last_byte(0x15);
+
+fn deploy_registry() -> Deployed<IssuerRegistry> {
+    Deployed::deploy(REGISTRY_ADDRESS, OWNER, Vec::new()).expect("registry deploys")
+}
+
+fn deploy_factory() -> Deployed<SBTFactory> {
+    Deployed::deploy(FACTORY_ADDRESS, OWNER, Vec::new()).expect("factory deploys")
+}
+
+fn deploy_sbt(issuer: Address, factory: Address) -> Deployed<SBT> {
+    let args = (
+        "Guild Badge".to_string(),
+        "GLD".to_string(),
+        issuer,
+        factory,
+        0u32,
+        0u32,
+        String::new(),
+    )
+        .abi_encode_params();
+    Deployed::deploy(SBT_ADDRESS, issuer, args).expect("sbt deploys")
+}
+
+/// Answer `reputationOf(account)` with `reputation` for a contract reading staking
+fn mock_reputation<C>(reader: &Deployed<C>, account: Address, reputation: U256) {
+    reader.vm.mock_static_call(
+        STAKING_ADDRESS,
+        IReputationStaking::reputationOfCall { account }.abi_encode(),
+        Ok(IReputationStaking::reputationOfCall::abi_encode_returns(&(
+            reputation,
+        ))),
+    );
+}
+
+#[test]
+fn reputable_issuer_registers_mints_and_factory_sees_supply() {
+    let registry = deploy_registry();
+    let factory = deploy_factory();
+
+    // Only applicants with at least 100 reputation may become issuers
+    let requirement = IIssuerRegistry::setReputationRequirementCall {
+        reputation_staking: STAKING_ADDRESS,
+        min_reputation: U256::from(100),
+    };
+    assert!(registry.call(OWNER, requirement).is_ok());
+    mock_reputation(&registry, ISSUER, U256::from(250));
+    mock_reputation(&registry, CAROL, U256::from(40));
+
+    assert!(registry
+        .call(ISSUER, IIssuerRegistry::registerAsIssuerCall {})
+        .is_ok());
+    let rejected = registry.call(CAROL, IIssuerRegistry::registerAsIssuerCall {});
+    assert!(matches!(
+        rejected.map_err(|data| InsufficientReputation::abi_decode(&data, true)),
+        Err(Ok(InsufficientReputation { provided, required }))
+            if provided == U256::from(40) && required == U256::from(100)
+    ));
+    assert!(matches!(
+        registry.call(OWNER, IIssuerRegistry::isIssuerCall { issuer_address: ISSUER }),
+        Ok(registered) if registered._0
+    ));
+
+    // The new issuer deploys a collection, lists it and mints
+    let sbt = deploy_sbt(ISSUER, FACTORY_ADDRESS);
+    let listing = ISBTFactory::registerSbtCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        name: "Guild Badge".to_string(),
+        symbol: "GLD".to_string(),
+    };
+    assert!(factory.call(ISSUER, listing).is_ok());
+    let minted = sbt.call(
+        ISSUER,
+        ISBT::mintToManyCall {
+            recipients: vec![ALICE, BOB],
+        },
+    );
+    assert!(matches!(minted, Ok(ids) if ids._0 == vec![U256::from(1), U256::from(2)]));
+
+    sbt.serve(&factory, ISBT::totalSupplyCall {});
+    assert!(matches!(
+        factory.call(OWNER, ISBTFactory::collectionSupplyCall { sbt_address: SBT_ADDRESS }),
+        Ok(supply) if supply._0 == U256::from(2)
+    ));
+    assert!(matches!(
+        factory.call(OWNER, ISBTFactory::issuerTotalMintedCall { issuer: ISSUER }),
+        Ok(total) if total._0 == U256::from(2)
+    ));
+}
+
+#[test]
+fn burning_a_token_lowers_factory_supply() {
+    let factory = deploy_factory();
+    let sbt = deploy_sbt(ISSUER, FACTORY_ADDRESS);
+    let listing = ISBTFactory::registerSbtCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        name: "Guild Badge".to_string(),
+        symbol: "GLD".to_string(),
+    };
+    assert!(factory.call(ISSUER, listing).is_ok());
+    let recipients = vec![ALICE, BOB, CAROL];
+    assert!(sbt
+        .call(ISSUER, ISBT::mintToManyCall { recipients })
+        .is_ok());
+
+    assert!(sbt
+        .call(
+            ISSUER,
+            ISBT::burnCall {
+                token_id: U256::from(2)
+            }
+        )
+        .is_ok());
+
+    sbt.serve(&factory, ISBT::totalSupplyCall {});
+    assert!(matches!(
+        factory.call(OWNER, ISBTFactory::collectionSupplyCall { sbt_address: SBT_ADDRESS }),
+        Ok(supply) if supply._0 == U256::from(2)
+    ));
+}
+
+#[test]
+fn factory_default_base_uri_reaches_token_uri() {
+    let factory = deploy_factory();
+    let sbt = deploy_sbt(ISSUER, FACTORY_ADDRESS);
+    assert!(sbt
+        .call(
+            ISSUER,
+            ISBT::mintToManyCall {
+                recipients: vec![ALICE]
+            }
+        )
+        .is_ok());
+
+    // No base URI configured: the SBT renders its own on-chain metadata
+    factory.serve(&sbt, ISBTFactory::defaultBaseUriCall {});
+    let generated = sbt.call(
+        ALICE,
+        ISBT::tokenURICall {
+            token_id: U256::from(1),
+        },
+    );
+    assert!(matches!(
+        generated,
+        Ok(uri) if uri._0.starts_with("data:application/json;base64,")
+    ));
+
+    let base_uri = ISBTFactory::setDefaultBaseUriCall {
+        base_uri: "https://meta.ethos.xyz/".to_string(),
+    };
+    assert!(factory.call(OWNER, base_uri).is_ok());
+    factory.serve(&sbt, ISBTFactory::defaultBaseUriCall {});
+    assert!(matches!(
+        sbt.call(ALICE, ISBT::tokenURICall { token_id: U256::from(1) }),
+        Ok(uri) if uri._0 == "https://meta.ethos.xyz/1"
+    ));
+}
+
+#[test]
+fn staking_reputation_reads_agree_across_consumers() {
+    let registry = deploy_registry();
+    let aggregator =
+        Deployed::<ReputationAggregator>::deploy(AGGREGATOR_ADDRESS, OWNER, Vec::new())
+            .expect("aggregator deploys");
+    let gate =
+        Deployed::<ReputationGate>::deploy(GATE_ADDRESS, OWNER, STAKING_ADDRESS.abi_encode())
+            .expect("gate deploys");
+
+    // One staking answer, read by all three consumers through the same calldata
+    mock_reputation(&registry, ALICE, U256::from(150));
+    mock_reputation(&aggregator, ALICE, U256::from(150));
+    mock_reputation(&gate, ALICE, U256::from(150));
+
+    let requirement = IIssuerRegistry::setReputationRequirementCall {
+        reputation_staking: STAKING_ADDRESS,
+        min_reputation: U256::from(100),
+    };
+    assert!(registry.call(OWNER, requirement).is_ok());
+    assert!(aggregator
+        .call(
+            OWNER,
+            IReputationAggregator::addPoolCall {
+                pool: STAKING_ADDRESS
+            }
+        )
+        .is_ok());
+    let tiers = IReputationGate::setTiersCall {
+        thresholds: vec![U256::from(50), U256::from(100), U256::from(500)],
+    };
+    assert!(gate.call(OWNER, tiers).is_ok());
+    assert!(gate
+        .call(
+            OWNER,
+            IReputationGate::setRequiredTierCall {
+                tier: U256::from(2)
+            }
+        )
+        .is_ok());
+
+    assert!(registry
+        .call(ALICE, IIssuerRegistry::registerAsIssuerCall {})
+        .is_ok());
+    assert!(matches!(
+        aggregator.call(OWNER, IReputationAggregator::totalReputationOfCall { account: ALICE }),
+        Ok(total) if total._0 == U256::from(150)
+    ));
+    assert!(matches!(
+        gate.call(OWNER, IReputationGate::tierOfCall { account: ALICE }),
+        Ok(tier) if tier._0 == U256::from(2)
+    ));
+    assert!(matches!(
+        gate.call(OWNER, IReputationGate::checkAccessCall { account: ALICE }),
+        Ok(access) if access._0
+    ));
+    // Nothing mocked for Bob: every consumer treats him as having no reputation
+    assert!(matches!(
+        gate.call(OWNER, IReputationGate::checkAccessCall { account: BOB }),
+        Ok(access) if !access._0
+    ));
+}
+
+#[test]
+fn factory_initializes_collection_deployed_without_issuer() {
+    let factory = deploy_factory();
+    // e.g. a CREATE2 deploy that could not know its issuer up front
+    let sbt = deploy_sbt(Address::ZERO, FACTORY_ADDRESS);
+    let listing = ISBTFactory::registerSbtCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        name: "Guild Badge".to_string(),
+        symbol: "GLD".to_string(),
+    };
+    assert!(factory.call(ISSUER, listing).is_ok());
+    let mint = || {
+        sbt.call(
+            ISSUER,
+            ISBT::mintToManyCall {
+                recipients: vec![ALICE],
+            },
+        )
+    };
+    assert!(mint().is_err());
+
+    // Only the factory may initialize, and only the factory owner may ask it to
+    assert!(sbt
+        .call(ISSUER, ISBT::initializeCall { issuer: ISSUER })
+        .is_err());
+    let initialize = ISBTFactory::initializeCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        issuer: ISSUER,
+    };
+    assert!(factory.call(ISSUER, initialize.clone()).is_err());
+
+    sbt.serve_call(&factory, IEthosSBT::initializeCall { issuer: ISSUER });
+    assert!(factory.call(OWNER, initialize.clone()).is_ok());
+    assert!(matches!(
+        sbt.call(ALICE, ISBT::getIssuerCall {}),
+        Ok(issuer) if issuer._0 == ISSUER
+    ));
+    assert!(mint().is_ok());
+
+    // A second initialization is refused by the collection, failing the factory call
+    sbt.serve_call(&factory, IEthosSBT::initializeCall { issuer: ISSUER });
+    assert!(factory.call(OWNER, initialize).is_err());
+}
+
+#[test]
+fn registering_someone_elses_collection_does_not_grant_its_issuer_role() {
+    let factory = deploy_factory();
+    let sbt = deploy_sbt(Address::ZERO, FACTORY_ADDRESS);
+
+    // Carol finds the issuer-less collection and registers it before its deployer does
+    let listing = ISBTFactory::registerSbtCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        name: "Guild Badge".to_string(),
+        symbol: "GLD".to_string(),
+    };
+    assert!(factory.call(CAROL, listing).is_ok());
+
+    // Nothing is served: the factory must refuse Carol before it calls the collection
+    let takeover = ISBTFactory::initializeCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        issuer: CAROL,
+    };
+    assert!(factory.call(CAROL, takeover).is_err());
+    assert!(sbt
+        .call(CAROL, ISBT::initializeCall { issuer: CAROL })
+        .is_err());
+    assert!(matches!(
+        sbt.call(CAROL, ISBT::getIssuerCall {}),
+        Ok(issuer) if issuer._0 == Address::ZERO
+    ));
+}
+
+#[test]
+fn factory_owner_recovers_lost_issuer() {
+    let factory = deploy_factory();
+    let sbt = deploy_sbt(ISSUER, FACTORY_ADDRESS);
+    let listing = ISBTFactory::registerSbtCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        name: "Guild Badge".to_string(),
+        symbol: "GLD".to_string(),
+    };
+    assert!(factory.call(ISSUER, listing).is_ok());
+
+    // Nobody but the factory can reassign the issuer on the collection itself
+    let reassign = ISBT::factorySetIssuerCall { new_issuer: CAROL };
+    assert!(sbt.call(CAROL, reassign.clone()).is_err());
+    assert!(sbt.call(OWNER, reassign).is_err());
+
+    // Recovery goes through the factory owner
+    let recover = ISBTFactory::recoverCollectionIssuerCall {
+        sbt_address: SBT_ADDRESS,
+        new_issuer: CAROL,
+    };
+    assert!(factory.call(ISSUER, recover.clone()).is_err());
+    sbt.serve_call(
+        &factory,
+        IEthosSBT::factorySetIssuerCall { new_issuer: CAROL },
+    );
+    assert!(factory.call(OWNER, recover).is_ok());
+    assert!(matches!(
+        sbt.call(ALICE, ISBT::getIssuerCall {}),
+        Ok(issuer) if issuer._0 == CAROL
+    ));
+    let logs = sbt.vm.get_emitted_logs();
+    let (topics, data) = &logs[logs.len() - 1];
+    let event = IssuerUpdated::decode_raw_log(topics.iter().copied(), data, true);
+    assert!(matches!(
+        event,
+        Ok(IssuerUpdated { previous_issuer, new_issuer })
+            if previous_issuer == ISSUER && new_issuer == CAROL
+    ));
+
+    // Minting rights moved with the issuer
+    let recipients = vec![ALICE];
+    assert!(sbt
+        .call(
+            ISSUER,
+            ISBT::mintToManyCall {
+                recipients: recipients.clone()
+            }
+        )
+        .is_err());
+    assert!(sbt.call(CAROL, ISBT::mintToManyCall { recipients }).is_ok());
+}
+
+#[test]
+fn minter_control_follows_the_collections_own_issuer() {
+    let factory = deploy_factory();
+    let sbt = deploy_sbt(ISSUER, FACTORY_ADDRESS);
+
+    // Carol lists Issuer's collection, but the factory asks the collection who its issuer is
+    let listing = ISBTFactory::registerSbtCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        name: "Guild Badge".to_string(),
+        symbol: "GLD".to_string(),
+    };
+    assert!(factory.call(CAROL, listing).is_ok());
+    sbt.serve(&factory, IEthosSBT::getIssuerCall {});
+
+    let grant = |minter| ISBTFactory::authorizeMinterCall {
+        sbt_address: SBT_ADDRESS,
+        minter,
+    };
+    assert!(factory.call(CAROL, grant(CAROL)).is_err());
+    assert!(factory.call(ISSUER, grant(BOB)).is_ok());
+    assert!(matches!(
+        factory.call(OWNER, ISBTFactory::isAuthorizedMinterCall { sbt_address: SBT_ADDRESS, minter: BOB }),
+        Ok(authorized) if authorized._0
+    ));
+    assert!(matches!(
+        factory.call(OWNER, ISBTFactory::isAuthorizedMinterCall { sbt_address: SBT_ADDRESS, minter: CAROL }),
+        Ok(authorized) if !authorized._0
+    ));
+}
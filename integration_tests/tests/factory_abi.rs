@@ -0,0 +1,61 @@
+use alloy_primitives::{address, Address, U256};
+use alloy_sol_types::sol;
+use integration_tests::Deployed;
+use sbt_factory::SBTFactory;
+
+// Views that clients read straight off the factory's ABI
+sol! {
+    interface ISBTFactory {
+        function registerSbtCollection(address sbt_address, string name, string symbol) external;
+        function isValidSbtContract(address sbt_address) external view returns (bool);
+        function isValidSbt(address sbt_address) external view returns (bool);
+        function getIssuerCollections(address issuer) external view returns ((string, string, address)[]);
+        function getTotalCollections() external view returns (uint256);
+    }
+}
+
+const OWNER: Address = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+const ISSUER: Address = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+const ALICE: Address = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+const FACTORY_ADDRESS: Address = Address::with_last_byte(0x11);
+const SBT_ADDRESS: Address = Address::with_last_byte(0x12);
+
+// `#[public]` routes every method in the impl, `pub` or not; this pins the
+// selectors so a rename or a dropped method shows up here.
+#[test]
+fn collection_views_are_reachable_through_the_abi() {
+    let factory = Deployed::<SBTFactory>::deploy(FACTORY_ADDRESS, OWNER, Vec::new())
+        .expect("factory deploys");
+    let listing = ISBTFactory::registerSbtCollectionCall {
+        sbt_address: SBT_ADDRESS,
+        name: "Guild Badge".to_string(),
+        symbol: "GLD".to_string(),
+    };
+    assert!(factory.call(ISSUER, listing).is_ok());
+
+    assert!(matches!(
+        factory.call(ALICE, ISBTFactory::isValidSbtContractCall { sbt_address: SBT_ADDRESS }),
+        Ok(valid) if valid._0
+    ));
+    assert!(matches!(
+        factory.call(ALICE, ISBTFactory::isValidSbtCall { sbt_address: SBT_ADDRESS }),
+        Ok(valid) if valid._0
+    ));
+    assert!(matches!(
+        factory.call(ALICE, ISBTFactory::isValidSbtCall { sbt_address: ALICE }),
+        Ok(valid) if !valid._0
+    ));
+    assert!(matches!(
+        factory.call(ALICE, ISBTFactory::getTotalCollectionsCall {}),
+        Ok(total) if total._0 == U256::from(1)
+    ));
+
+    let collections = factory.call(
+        ALICE,
+        ISBTFactory::getIssuerCollectionsCall { issuer: ISSUER },
+    );
+    assert!(matches!(
+        collections,
+        Ok(list) if list._0 == vec![("Guild Badge".to_string(), "GLD".to_string(), SBT_ADDRESS)]
+    ));
+}
@@ -9,29 +9,76 @@
 #[macro_use]
 extern crate alloc;
 
-use alloc::vec::Vec;
-use alloy_primitives::Address;
-use stylus_sdk::{alloy_sol_types::sol, prelude::*};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use alloy_sol_types::{Eip712Domain, SolCall, SolStruct};
+use stylus_sdk::{abi::Bytes, alloy_sol_types::sol, prelude::*, stylus_core::calls::context::Call};
 
 sol! {
     // Errors
     error Unauthorized();
-    error AccountAlreadyRegistered();
+    error AccountAlreadyRegistered(address account);
+    error IssuerNotRegistered(address account);
     error AddressZeroNotAllowed();
     error NotPendingOwner();
+    error RenounceNotInitiated();
+    error RenounceWindowExpired();
+    error SetupAlreadyFinished();
+    error InsufficientReputation(uint256 provided, uint256 required);
+    error InvalidSignature();
+    error ExpiredSignature();
+    error BatchTooLarge(uint256 length, uint256 max);
 
     //Events
     event IssuerRegistered(address indexed issuer);
-    event NewOwnerRegistered(address indexed new_owner);
+    event IssuerRevoked(address indexed issuer);
+    event OwnershipTransferInitiated(address indexed previous_owner, address indexed new_owner);
+    event OwnershipTransferCancelled(address indexed owner, address indexed cancelled_owner);
     event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+    event RenounceInitiated(address indexed owner, uint256 deadline);
+    event RenounceConfirmed(address indexed previous_owner);
+    event SetupFinished();
+    event ReputationRequirementUpdated(address indexed reputation_staking, uint256 min_reputation);
 }
 
+sol! {
+    /// EIP-712 payload the owner signs to pre-approve an issuer
+    struct IssuerApproval {
+        address applicant;
+        uint256 nonce;
+        uint256 deadline;
+    }
+}
+
+sol! {
+    interface IReputationStaking {
+        function reputationOf(address account) external view returns (uint256);
+    }
+}
+
+/// Seconds the owner has to confirm a renounce after initiating it
+const RENOUNCE_WINDOW: u64 = 3600;
+
+/// Maximum number of entries accepted by batch views
+const MAX_BATCH_SIZE: usize = 100;
+
+/// ERC-165 id of `IIssuerRegistry`: `isIssuer(address) ^ registerAsIssuer()`
+const IISSUER_REGISTRY_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x1a, 0x4d, 0xa3, 0x9a]);
+
 #[derive(SolidityError)]
 pub enum IssuerRegistryError {
     Unauthorized(Unauthorized),
     AccountAlreadyRegistered(AccountAlreadyRegistered),
+    IssuerNotRegistered(IssuerNotRegistered),
     AddressZeroNotAllowed(AddressZeroNotAllowed),
     NotPendingOwner(NotPendingOwner),
+    RenounceNotInitiated(RenounceNotInitiated),
+    RenounceWindowExpired(RenounceWindowExpired),
+    SetupAlreadyFinished(SetupAlreadyFinished),
+    InsufficientReputation(InsufficientReputation),
+    InvalidSignature(InvalidSignature),
+    ExpiredSignature(ExpiredSignature),
+    BatchTooLarge(BatchTooLarge),
 }
 
 sol_storage! {
@@ -40,6 +87,86 @@ sol_storage! {
         address owner;
         address pending_owner;
         mapping(address => bool) is_registered;
+        /// Minimum reputation an applicant needs; zero disables the check
+        uint256 issuer_min_reputation;
+        /// Staking contract queried for applicant reputation
+        address reputation_staking;
+        /// Per-applicant nonce consumed by each owner-signed approval
+        mapping(address => uint256) approval_nonces;
+        /// Last timestamp at which `confirm_renounce` is accepted; zero when none is pending
+        uint256 renounce_deadline;
+        /// Set by `finish_setup`; afterwards only the two-step ownership handoff works
+        bool setup_complete;
+        /// Reserved slots so future fields can be appended without shifting layout.
+        /// New fields go above this line; shrink the gap by the slots they use.
+        uint256[45] __gap;
+    }
+}
+
+impl IssuerRegistry {
+    /// Internal function to read an applicant's reputation from the staking contract.
+    /// A failed or malformed call counts as zero reputation.
+    fn applicant_reputation(&self, applicant: Address) -> U256 {
+        let calldata = IReputationStaking::reputationOfCall { account: applicant }.abi_encode();
+        let Ok(returned) =
+            self.vm()
+                .static_call(&Call::new(), self.reputation_staking.get(), &calldata)
+        else {
+            return U256::ZERO;
+        };
+        IReputationStaking::reputationOfCall::abi_decode_returns(&returned, true)
+            .map(|reputation| reputation._0)
+            .unwrap_or(U256::ZERO)
+    }
+
+    /// Internal function to mark an address as an issuer and announce it
+    fn record_issuer(&mut self, issuer_address: Address) {
+        self.is_registered.insert(issuer_address, true);
+
+        log(
+            self.vm(),
+            IssuerRegistered {
+                issuer: issuer_address,
+            },
+        );
+    }
+
+    /// EIP-712 domain binding approvals to this chain and this registry
+    fn approval_domain(&self) -> Eip712Domain {
+        Eip712Domain::new(
+            Some(Cow::Borrowed("IssuerRegistry")),
+            Some(Cow::Borrowed("1")),
+            Some(U256::from(self.vm().chain_id())),
+            Some(self.vm().contract_address()),
+            None,
+        )
+    }
+
+    /// Internal function to recover the signer of a 65-byte `r || s || v` signature
+    /// via the ecrecover precompile. Returns the zero address when recovery fails.
+    fn recover_signer(&self, digest: B256, signature: &[u8]) -> Address {
+        if signature.len() != 65 {
+            return Address::ZERO;
+        }
+        let v = match signature[64] {
+            v @ (27 | 28) => v,
+            v @ (0 | 1) => v + 27,
+            _ => return Address::ZERO,
+        };
+
+        let mut input = Vec::with_capacity(128);
+        input.extend_from_slice(digest.as_slice());
+        input.extend_from_slice(&[0u8; 31]);
+        input.push(v);
+        input.extend_from_slice(&signature[..64]);
+
+        match self
+            .vm()
+            .static_call(&Call::new(), Address::with_last_byte(1), &input)
+        {
+            Ok(returned) if returned.len() == 32 => Address::from_slice(&returned[12..]),
+            _ => Address::ZERO,
+        }
     }
 }
 
@@ -79,15 +206,47 @@ impl IssuerRegistry {
 
         if self.is_registered.get(issuer_address) {
             return Err(IssuerRegistryError::AccountAlreadyRegistered(
-                AccountAlreadyRegistered {},
+                AccountAlreadyRegistered {
+                    account: issuer_address,
+                },
             ));
         }
 
-        self.is_registered.insert(issuer_address, true);
+        let min_reputation = self.issuer_min_reputation.get();
+        if !min_reputation.is_zero() {
+            let reputation = self.applicant_reputation(issuer_address);
+            if reputation < min_reputation {
+                return Err(IssuerRegistryError::InsufficientReputation(
+                    InsufficientReputation {
+                        provided: reputation,
+                        required: min_reputation,
+                    },
+                ));
+            }
+        }
+
+        self.record_issuer(issuer_address);
+
+        Ok(())
+    }
+
+    /// Remove the caller from the registry; they can register again later
+    fn renounce_issuer(&mut self) -> Result<(), IssuerRegistryError> {
+        let issuer_address = self.vm().msg_sender();
+
+        if !self.is_registered.get(issuer_address) {
+            return Err(IssuerRegistryError::IssuerNotRegistered(
+                IssuerNotRegistered {
+                    account: issuer_address,
+                },
+            ));
+        }
+
+        self.is_registered.insert(issuer_address, false);
 
         log(
             self.vm(),
-            IssuerRegistered {
+            IssuerRevoked {
                 issuer: issuer_address,
             },
         );
@@ -95,6 +254,53 @@ impl IssuerRegistry {
         Ok(())
     }
 
+    /// Register the caller using an EIP-712 approval signed by the owner.
+    /// The owner's signature stands in for the reputation requirement.
+    fn register_with_approval(
+        &mut self,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), IssuerRegistryError> {
+        let issuer_address = self.vm().msg_sender();
+
+        if issuer_address.is_zero() {
+            return Err(IssuerRegistryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        if self.is_registered.get(issuer_address) {
+            return Err(IssuerRegistryError::AccountAlreadyRegistered(
+                AccountAlreadyRegistered {
+                    account: issuer_address,
+                },
+            ));
+        }
+
+        if U256::from(self.vm().block_timestamp()) > deadline {
+            return Err(IssuerRegistryError::ExpiredSignature(ExpiredSignature {}));
+        }
+
+        let nonce = self.approval_nonces.get(issuer_address);
+        let approval = IssuerApproval {
+            applicant: issuer_address,
+            nonce,
+            deadline,
+        };
+        let digest = approval.eip712_signing_hash(&self.approval_domain());
+
+        let signer = self.recover_signer(digest, &signature);
+        if signer.is_zero() || signer != self.owner.get() {
+            return Err(IssuerRegistryError::InvalidSignature(InvalidSignature {}));
+        }
+
+        self.approval_nonces
+            .insert(issuer_address, nonce + U256::from(1));
+        self.record_issuer(issuer_address);
+
+        Ok(())
+    }
+
     fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), IssuerRegistryError> {
         let current_owner = self.owner.get();
         if current_owner != self.vm().msg_sender() {
@@ -109,7 +315,37 @@ impl IssuerRegistry {
 
         self.pending_owner.set(new_owner);
 
-        log(self.vm(), NewOwnerRegistered { new_owner });
+        log(
+            self.vm(),
+            OwnershipTransferInitiated {
+                previous_owner: self.owner.get(),
+                new_owner,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw a pending ownership transfer before it is accepted
+    fn cancel_ownership_transfer(&mut self) -> Result<(), IssuerRegistryError> {
+        if self.owner.get() != self.vm().msg_sender() {
+            return Err(IssuerRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        let cancelled_owner = self.pending_owner.get();
+        if cancelled_owner.is_zero() {
+            return Err(IssuerRegistryError::NotPendingOwner(NotPendingOwner {}));
+        }
+
+        self.pending_owner.set(Address::ZERO);
+
+        log(
+            self.vm(),
+            OwnershipTransferCancelled {
+                owner: self.owner.get(),
+                cancelled_owner,
+            },
+        );
 
         Ok(())
     }
@@ -131,6 +367,7 @@ impl IssuerRegistry {
         let previous_owner = self.owner.get();
         self.owner.set(pending_owner);
         self.pending_owner.set(Address::ZERO);
+        self.renounce_deadline.set(U256::ZERO);
 
         log(
             self.vm(),
@@ -143,10 +380,54 @@ impl IssuerRegistry {
         Ok(())
     }
 
+    /// Require applicants to hold `min_reputation` in `reputation_staking`; zero disables it
+    fn set_reputation_requirement(
+        &mut self,
+        reputation_staking: Address,
+        min_reputation: U256,
+    ) -> Result<(), IssuerRegistryError> {
+        if self.owner.get() != self.vm().msg_sender() {
+            return Err(IssuerRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        if !min_reputation.is_zero() && reputation_staking.is_zero() {
+            return Err(IssuerRegistryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.reputation_staking.set(reputation_staking);
+        self.issuer_min_reputation.set(min_reputation);
+
+        log(
+            self.vm(),
+            ReputationRequirementUpdated {
+                reputation_staking,
+                min_reputation,
+            },
+        );
+
+        Ok(())
+    }
+
     fn is_issuer(&self, issuer_address: Address) -> bool {
         self.is_registered.get(issuer_address)
     }
 
+    /// Registration status of each address, in order
+    fn are_issuers(&self, addresses: Vec<Address>) -> Result<Vec<bool>, IssuerRegistryError> {
+        if addresses.len() > MAX_BATCH_SIZE {
+            return Err(IssuerRegistryError::BatchTooLarge(BatchTooLarge {
+                length: U256::from(addresses.len()),
+                max: U256::from(MAX_BATCH_SIZE),
+            }));
+        }
+        Ok(addresses
+            .iter()
+            .map(|address| self.is_issuer(*address))
+            .collect())
+    }
+
     fn get_owner(&self) -> Address {
         self.owner.get()
     }
@@ -154,14 +435,163 @@ impl IssuerRegistry {
     fn get_pending_owner(&self) -> Address {
         self.pending_owner.get()
     }
+
+    /// Hand ownership over in one step while the deployment is still being set up
+    fn transfer_ownership_immediate(
+        &mut self,
+        new_owner: Address,
+    ) -> Result<(), IssuerRegistryError> {
+        if self.owner.get() != self.vm().msg_sender() {
+            return Err(IssuerRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        if self.setup_complete.get() {
+            return Err(IssuerRegistryError::SetupAlreadyFinished(
+                SetupAlreadyFinished {},
+            ));
+        }
+
+        if new_owner.is_zero() {
+            return Err(IssuerRegistryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(new_owner);
+        self.pending_owner.set(Address::ZERO);
+        self.renounce_deadline.set(U256::ZERO);
+
+        log(
+            self.vm(),
+            OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Lock the registry out of setup mode for good
+    fn finish_setup(&mut self) -> Result<(), IssuerRegistryError> {
+        if self.owner.get() != self.vm().msg_sender() {
+            return Err(IssuerRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        if self.setup_complete.get() {
+            return Err(IssuerRegistryError::SetupAlreadyFinished(
+                SetupAlreadyFinished {},
+            ));
+        }
+
+        self.setup_complete.set(true);
+
+        log(self.vm(), SetupFinished {});
+        Ok(())
+    }
+
+    fn is_setup_complete(&self) -> bool {
+        self.setup_complete.get()
+    }
+
+    /// First step of giving up ownership for good. `confirm_renounce` must follow
+    /// within `RENOUNCE_WINDOW` seconds; calling this again restarts the window.
+    fn initiate_renounce(&mut self) -> Result<(), IssuerRegistryError> {
+        if self.owner.get() != self.vm().msg_sender() {
+            return Err(IssuerRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        let deadline = U256::from(self.vm().block_timestamp()) + U256::from(RENOUNCE_WINDOW);
+        self.renounce_deadline.set(deadline);
+
+        log(
+            self.vm(),
+            RenounceInitiated {
+                owner: self.owner.get(),
+                deadline,
+            },
+        );
+        Ok(())
+    }
+
+    /// Clear the owner permanently. Admin functions are unusable afterwards.
+    fn confirm_renounce(&mut self) -> Result<(), IssuerRegistryError> {
+        if self.owner.get() != self.vm().msg_sender() {
+            return Err(IssuerRegistryError::Unauthorized(Unauthorized {}));
+        }
+
+        let deadline = self.renounce_deadline.get();
+        if deadline.is_zero() {
+            return Err(IssuerRegistryError::RenounceNotInitiated(
+                RenounceNotInitiated {},
+            ));
+        }
+        if U256::from(self.vm().block_timestamp()) > deadline {
+            return Err(IssuerRegistryError::RenounceWindowExpired(
+                RenounceWindowExpired {},
+            ));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(Address::ZERO);
+        self.pending_owner.set(Address::ZERO);
+        self.renounce_deadline.set(U256::ZERO);
+
+        log(self.vm(), RenounceConfirmed { previous_owner });
+        log(
+            self.vm(),
+            OwnershipTransferred {
+                previous_owner,
+                new_owner: Address::ZERO,
+            },
+        );
+        Ok(())
+    }
+
+    fn get_renounce_deadline(&self) -> U256 {
+        self.renounce_deadline.get()
+    }
+
+    fn get_issuer_min_reputation(&self) -> U256 {
+        self.issuer_min_reputation.get()
+    }
+
+    fn get_reputation_staking(&self) -> Address {
+        self.reputation_staking.get()
+    }
+
+    fn get_approval_nonce(&self, applicant: Address) -> U256 {
+        self.approval_nonces.get(applicant)
+    }
+
+    /// Returns the deployed contract version
+    fn version(&self) -> String {
+        String::from(env!("CARGO_PKG_VERSION"))
+    }
+
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        // ERC165 interface ID: 0x01ffc9a7
+        // IIssuerRegistry interface ID: 0x1a4da39a
+        interface_id == FixedBytes([0x01, 0xff, 0xc9, 0xa7]) || // ERC165
+            interface_id == IISSUER_REGISTRY_INTERFACE_ID // IIssuerRegistry
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::address;
+    use alloy_primitives::{address, keccak256};
+    use alloy_sol_types::{SolError, SolEvent, SolValue};
+    use ethers::{
+        signers::{LocalWallet, Signer},
+        types::H256,
+    };
     use stylus_sdk::testing::*;
 
+    /// First slot past the registry layout (end of the gap); adding fields must not move it
+    const REGISTRY_STORAGE_END: u64 = 53;
+
     fn setup_contract() -> (TestVM, IssuerRegistry) {
         let vm = TestVM::default();
         let mut contract = IssuerRegistry::from(&vm);
@@ -245,7 +675,9 @@ mod tests {
         let result = contract.register_as_issuer();
         assert!(matches!(
             result,
-            Err(IssuerRegistryError::AccountAlreadyRegistered(_))
+            Err(IssuerRegistryError::AccountAlreadyRegistered(
+                AccountAlreadyRegistered { account }
+            )) if account == alice
         ));
     }
 
@@ -262,6 +694,54 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_renounce_issuer() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+
+        vm.set_sender(alice);
+        assert!(contract.register_as_issuer().is_ok());
+        vm.set_sender(bob);
+        assert!(contract.register_as_issuer().is_ok());
+
+        vm.set_sender(alice);
+        assert!(contract.renounce_issuer().is_ok());
+        assert!(!contract.is_issuer(alice));
+        // Other issuers are untouched
+        assert!(contract.is_issuer(bob));
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = IssuerRevoked::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(event, Ok(IssuerRevoked { issuer }) if issuer == alice));
+
+        // Renouncing is not permanent
+        assert!(contract.register_as_issuer().is_ok());
+        assert!(contract.is_issuer(alice));
+    }
+
+    #[test]
+    fn test_renounce_issuer_unregistered() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.renounce_issuer(),
+            Err(IssuerRegistryError::IssuerNotRegistered(
+                IssuerNotRegistered { account }
+            )) if account == alice
+        ));
+
+        assert!(contract.register_as_issuer().is_ok());
+        assert!(contract.renounce_issuer().is_ok());
+        assert!(matches!(
+            contract.renounce_issuer(),
+            Err(IssuerRegistryError::IssuerNotRegistered(_))
+        ));
+    }
+
     #[test]
     fn test_is_issuer_unregistered_address() {
         let (_vm, contract) = setup_contract();
@@ -270,6 +750,42 @@ mod tests {
         assert!(!contract.is_issuer(alice));
     }
 
+    #[test]
+    fn test_are_issuers_mixed() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let carol = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+
+        vm.set_sender(alice);
+        assert!(contract.register_as_issuer().is_ok());
+        vm.set_sender(carol);
+        assert!(contract.register_as_issuer().is_ok());
+
+        let result = contract.are_issuers(vec![alice, bob, carol, Address::ZERO, alice]);
+        assert!(matches!(
+            result,
+            Ok(statuses) if statuses == vec![true, false, true, false, true]
+        ));
+        assert!(matches!(contract.are_issuers(Vec::new()), Ok(statuses) if statuses.is_empty()));
+    }
+
+    #[test]
+    fn test_are_issuers_too_large() {
+        let (_vm, contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        let at_cap = contract.are_issuers(vec![alice; MAX_BATCH_SIZE]);
+        assert!(matches!(at_cap, Ok(statuses) if statuses.len() == MAX_BATCH_SIZE));
+
+        let over_cap = contract.are_issuers(vec![alice; MAX_BATCH_SIZE + 1]);
+        assert!(matches!(
+            over_cap,
+            Err(IssuerRegistryError::BatchTooLarge(BatchTooLarge { length, max }))
+                if length == U256::from(MAX_BATCH_SIZE + 1) && max == U256::from(MAX_BATCH_SIZE)
+        ));
+    }
+
     // OWNERSHIP TRANSFER TESTS
 
     #[test]
@@ -383,4 +899,599 @@ mod tests {
             Err(IssuerRegistryError::AddressZeroNotAllowed(_))
         ));
     }
+
+    #[test]
+    fn test_pending_owner_tracks_transfer_lifecycle() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(contract.transfer_ownership(alice).is_ok());
+        assert_eq!(contract.get_pending_owner(), alice);
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = OwnershipTransferInitiated::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(OwnershipTransferInitiated { previous_owner, new_owner })
+                if previous_owner == owner && new_owner == alice
+        ));
+
+        vm.set_sender(alice);
+        assert!(contract.accept_ownership().is_ok());
+        assert_eq!(contract.get_pending_owner(), Address::ZERO);
+    }
+
+    #[test]
+    fn test_cancel_ownership_transfer() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(contract.transfer_ownership(alice).is_ok());
+        assert!(contract.cancel_ownership_transfer().is_ok());
+        assert_eq!(contract.get_pending_owner(), Address::ZERO);
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = OwnershipTransferCancelled::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(OwnershipTransferCancelled { owner: emitted_owner, cancelled_owner })
+                if emitted_owner == owner && cancelled_owner == alice
+        ));
+
+        // The cancelled target can no longer accept
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.accept_ownership(),
+            Err(IssuerRegistryError::NotPendingOwner(_))
+        ));
+        assert_eq!(contract.get_owner(), owner);
+    }
+
+    #[test]
+    fn test_cancel_ownership_transfer_guards() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        // Nothing pending yet
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.cancel_ownership_transfer(),
+            Err(IssuerRegistryError::NotPendingOwner(_))
+        ));
+
+        assert!(contract.transfer_ownership(alice).is_ok());
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.cancel_ownership_transfer(),
+            Err(IssuerRegistryError::Unauthorized(_))
+        ));
+        assert_eq!(contract.get_pending_owner(), alice);
+    }
+
+    // REPUTATION REQUIREMENT TESTS
+
+    fn mock_reputation(vm: &TestVM, staking: Address, account: Address, reputation: U256) {
+        vm.mock_static_call(
+            staking,
+            IReputationStaking::reputationOfCall { account }.abi_encode(),
+            Ok(IReputationStaking::reputationOfCall::abi_encode_returns(&(
+                reputation,
+            ))),
+        );
+    }
+
+    #[test]
+    fn test_register_above_min_reputation() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(contract
+            .set_reputation_requirement(staking, U256::from(100))
+            .is_ok());
+        assert_eq!(contract.get_reputation_staking(), staking);
+        assert_eq!(contract.get_issuer_min_reputation(), U256::from(100));
+
+        mock_reputation(&vm, staking, alice, U256::from(100));
+
+        vm.set_sender(alice);
+        assert!(contract.register_as_issuer().is_ok());
+        assert!(contract.is_issuer(alice));
+    }
+
+    #[test]
+    fn test_register_below_min_reputation() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(contract
+            .set_reputation_requirement(staking, U256::from(100))
+            .is_ok());
+
+        mock_reputation(&vm, staking, alice, U256::from(99));
+
+        vm.set_sender(alice);
+        let result = contract.register_as_issuer();
+        assert!(matches!(
+            result,
+            Err(IssuerRegistryError::InsufficientReputation(
+                InsufficientReputation { provided, required }
+            )) if provided == U256::from(99) && required == U256::from(100)
+        ));
+        assert!(!contract.is_issuer(alice));
+    }
+
+    #[test]
+    fn test_insufficient_reputation_revert_data() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(contract
+            .set_reputation_requirement(staking, U256::from(100))
+            .is_ok());
+
+        // An unreachable staking contract counts as zero reputation
+        vm.set_sender(alice);
+        let result = contract.register_as_issuer();
+        assert!(result.is_err());
+
+        if let Err(err) = result {
+            let revert_data: Vec<u8> = err.into();
+            let decoded = InsufficientReputation::abi_decode(&revert_data, true);
+            assert!(matches!(
+                decoded,
+                Ok(InsufficientReputation { provided, required })
+                    if provided == U256::ZERO && required == U256::from(100)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_zero_min_reputation_disables_check() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(contract
+            .set_reputation_requirement(staking, U256::ZERO)
+            .is_ok());
+
+        // No mock: the staking contract must not be consulted
+        vm.set_sender(alice);
+        assert!(contract.register_as_issuer().is_ok());
+    }
+
+    #[test]
+    fn test_set_reputation_requirement_unauthorized() {
+        let (vm, mut contract) = setup_contract();
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        let result = contract.set_reputation_requirement(staking, U256::from(100));
+        assert!(matches!(result, Err(IssuerRegistryError::Unauthorized(_))));
+    }
+
+    #[test]
+    fn test_set_reputation_requirement_zero_staking() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        let result = contract.set_reputation_requirement(Address::ZERO, U256::from(100));
+        assert!(matches!(
+            result,
+            Err(IssuerRegistryError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    // STORAGE LAYOUT TESTS
+
+    #[test]
+    fn test_storage_layout_with_gap() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(contract.register_as_issuer().is_ok());
+
+        // Existing fields still read back and the gap stays untouched
+        assert!(contract.is_issuer(alice));
+        assert_eq!(contract.get_owner(), owner);
+        for i in 0..contract.__gap.len() {
+            assert_eq!(contract.__gap.get(i), Some(U256::ZERO));
+        }
+
+        // The last gap slot sits right before the fixed end of the layout
+        let sentinel = U256::from(0xe7405);
+        let last = contract.__gap.len() - 1;
+        if let Some(mut slot) = contract.__gap.setter(last) {
+            slot.set(sentinel);
+        }
+        assert_eq!(
+            vm.get_storage(U256::from(REGISTRY_STORAGE_END - 1)),
+            B256::from(sentinel)
+        );
+    }
+
+    // SIGNED APPROVAL TESTS
+
+    /// Well-known dev key for the test owner `0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266`
+    const OWNER_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    /// Build the EIP-712 digest by hand so the contract's encoding is checked independently
+    fn approval_digest(
+        contract: &IssuerRegistry,
+        applicant: Address,
+        nonce: U256,
+        deadline: U256,
+    ) -> B256 {
+        let domain_typehash = keccak256(
+            "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+        );
+        let domain_separator = keccak256(
+            (
+                domain_typehash,
+                keccak256("IssuerRegistry"),
+                keccak256("1"),
+                U256::from(contract.vm().chain_id()),
+                contract.vm().contract_address(),
+            )
+                .abi_encode(),
+        );
+        let approval_typehash =
+            keccak256("IssuerApproval(address applicant,uint256 nonce,uint256 deadline)");
+        let struct_hash = keccak256((approval_typehash, applicant, nonce, deadline).abi_encode());
+
+        let mut payload = vec![0x19, 0x01];
+        payload.extend_from_slice(domain_separator.as_slice());
+        payload.extend_from_slice(struct_hash.as_slice());
+        keccak256(payload)
+    }
+
+    /// Sign `digest` with `key` and mock the ecrecover precompile to return the signer
+    fn sign_approval(vm: &TestVM, key: &str, digest: B256) -> Vec<u8> {
+        let wallet: LocalWallet = key.parse().unwrap();
+        let signature = wallet.sign_hash(H256::from(digest.0)).unwrap();
+        let signer = signature.recover(H256::from(digest.0)).unwrap();
+        assert_eq!(signer, wallet.address());
+
+        let bytes = signature.to_vec();
+        let mut input = digest.to_vec();
+        input.extend_from_slice(&[0u8; 31]);
+        input.push(bytes[64]);
+        input.extend_from_slice(&bytes[..64]);
+
+        let mut recovered = vec![0u8; 12];
+        recovered.extend_from_slice(signer.as_bytes());
+        vm.mock_static_call(Address::with_last_byte(1), input, Ok(recovered));
+
+        bytes
+    }
+
+    #[test]
+    fn test_register_with_approval_success() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let deadline = U256::from(1_000);
+
+        let digest = approval_digest(&contract, alice, U256::ZERO, deadline);
+        let signature = sign_approval(&vm, OWNER_KEY, digest);
+
+        vm.set_sender(alice);
+        let result = contract.register_with_approval(deadline, signature.into());
+        assert!(result.is_ok());
+        assert!(contract.is_issuer(alice));
+        assert_eq!(contract.get_approval_nonce(alice), U256::from(1));
+    }
+
+    #[test]
+    fn test_register_with_approval_wrong_signer() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let deadline = U256::from(1_000);
+
+        // A non-owner key cannot approve anyone, including its own address
+        let alice_key = "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d";
+        let digest = approval_digest(&contract, alice, U256::ZERO, deadline);
+        let signature = sign_approval(&vm, alice_key, digest);
+
+        vm.set_sender(alice);
+        let result = contract.register_with_approval(deadline, signature.into());
+        assert!(matches!(
+            result,
+            Err(IssuerRegistryError::InvalidSignature(_))
+        ));
+        assert!(!contract.is_issuer(alice));
+    }
+
+    #[test]
+    fn test_register_with_approval_for_other_applicant() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let deadline = U256::from(1_000);
+
+        // An approval for Alice does not work for Bob
+        let digest = approval_digest(&contract, alice, U256::ZERO, deadline);
+        let signature = sign_approval(&vm, OWNER_KEY, digest);
+
+        vm.set_sender(bob);
+        let result = contract.register_with_approval(deadline, signature.into());
+        assert!(matches!(
+            result,
+            Err(IssuerRegistryError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn test_register_with_approval_replay() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let deadline = U256::from(1_000);
+
+        let digest = approval_digest(&contract, alice, U256::ZERO, deadline);
+        let signature = sign_approval(&vm, OWNER_KEY, digest);
+
+        vm.set_sender(alice);
+        assert!(contract
+            .register_with_approval(deadline, signature.clone().into())
+            .is_ok());
+
+        // Deregister Alice by hand so only the consumed nonce stands in the way
+        contract.is_registered.insert(alice, false);
+
+        vm.set_sender(alice);
+        let result = contract.register_with_approval(deadline, signature.into());
+        assert!(matches!(
+            result,
+            Err(IssuerRegistryError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn test_register_with_approval_expired() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let deadline = U256::from(1_000);
+
+        let digest = approval_digest(&contract, alice, U256::ZERO, deadline);
+        let signature = sign_approval(&vm, OWNER_KEY, digest);
+
+        vm.set_block_timestamp(1_001);
+        vm.set_sender(alice);
+        let result = contract.register_with_approval(deadline, signature.into());
+        assert!(matches!(
+            result,
+            Err(IssuerRegistryError::ExpiredSignature(_))
+        ));
+    }
+
+    #[test]
+    fn test_register_with_approval_malformed_signature() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        let result = contract.register_with_approval(U256::from(1_000), vec![0u8; 64].into());
+        assert!(matches!(
+            result,
+            Err(IssuerRegistryError::InvalidSignature(_))
+        ));
+    }
+
+    // INTROSPECTION TESTS
+
+    sol! {
+        interface IIssuerRegistry {
+            function isIssuer(address issuer_address) external view returns (bool);
+            function registerAsIssuer() external;
+        }
+    }
+
+    #[test]
+    fn test_version() {
+        let (_vm, contract) = setup_contract();
+        assert_eq!(contract.version(), "0.1.0");
+    }
+
+    #[test]
+    fn test_supports_interface() {
+        let (_vm, contract) = setup_contract();
+
+        // ERC165
+        assert!(contract.supports_interface(FixedBytes([0x01, 0xff, 0xc9, 0xa7])));
+
+        // IIssuerRegistry id matches the XOR of its selectors
+        let is_issuer = IIssuerRegistry::isIssuerCall::SELECTOR;
+        let register = IIssuerRegistry::registerAsIssuerCall::SELECTOR;
+        let expected: [u8; 4] = core::array::from_fn(|i| is_issuer[i] ^ register[i]);
+        assert_eq!(IISSUER_REGISTRY_INTERFACE_ID, FixedBytes(expected));
+        assert!(contract.supports_interface(IISSUER_REGISTRY_INTERFACE_ID));
+
+        // Unsupported interfaces
+        assert!(!contract.supports_interface(FixedBytes([0x80, 0xac, 0x58, 0xcd])));
+        assert!(!contract.supports_interface(FixedBytes([0xff, 0xff, 0xff, 0xff])));
+    }
+
+    // SETUP TESTS
+
+    #[test]
+    fn test_transfer_ownership_immediate_during_setup() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+
+        vm.set_sender(owner);
+        assert!(contract.transfer_ownership(bob).is_ok());
+        assert!(contract.transfer_ownership_immediate(alice).is_ok());
+        assert_eq!(contract.get_owner(), alice);
+        // An earlier nomination does not survive the direct transfer
+        assert_eq!(contract.get_pending_owner(), Address::ZERO);
+
+        // The old owner has lost control
+        assert!(matches!(
+            contract.transfer_ownership_immediate(owner),
+            Err(IssuerRegistryError::Unauthorized(_))
+        ));
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.transfer_ownership_immediate(Address::ZERO),
+            Err(IssuerRegistryError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_ownership_immediate_after_setup() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(!contract.is_setup_complete());
+        assert!(contract.finish_setup().is_ok());
+        assert!(contract.is_setup_complete());
+
+        assert!(matches!(
+            contract.transfer_ownership_immediate(alice),
+            Err(IssuerRegistryError::SetupAlreadyFinished(_))
+        ));
+        assert!(matches!(
+            contract.finish_setup(),
+            Err(IssuerRegistryError::SetupAlreadyFinished(_))
+        ));
+        assert_eq!(contract.get_owner(), owner);
+
+        // The two-step handoff still works
+        assert!(contract.transfer_ownership(alice).is_ok());
+        vm.set_sender(alice);
+        assert!(contract.accept_ownership().is_ok());
+        assert_eq!(contract.get_owner(), alice);
+    }
+
+    #[test]
+    fn test_finish_setup_unauthorized() {
+        let (vm, mut contract) = setup_contract();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.finish_setup(),
+            Err(IssuerRegistryError::Unauthorized(_))
+        ));
+        assert!(!contract.is_setup_complete());
+    }
+
+    // RENOUNCE TESTS
+
+    #[test]
+    fn test_renounce_two_step() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        vm.set_block_timestamp(1_000);
+        assert!(contract.initiate_renounce().is_ok());
+        assert_eq!(
+            contract.get_renounce_deadline(),
+            U256::from(1_000 + RENOUNCE_WINDOW)
+        );
+        // Still the owner until confirmed
+        assert_eq!(contract.get_owner(), owner);
+
+        vm.set_block_timestamp(1_000 + RENOUNCE_WINDOW);
+        assert!(contract.confirm_renounce().is_ok());
+        assert_eq!(contract.get_owner(), Address::ZERO);
+        assert_eq!(contract.get_renounce_deadline(), U256::ZERO);
+
+        // Admin functions are gone for good
+        assert!(matches!(
+            contract.initiate_renounce(),
+            Err(IssuerRegistryError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            contract.transfer_ownership(owner),
+            Err(IssuerRegistryError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_confirm_renounce_without_initiate() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.confirm_renounce(),
+            Err(IssuerRegistryError::RenounceNotInitiated(_))
+        ));
+        assert_eq!(contract.get_owner(), owner);
+    }
+
+    #[test]
+    fn test_confirm_renounce_after_window() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        vm.set_block_timestamp(1_000);
+        assert!(contract.initiate_renounce().is_ok());
+
+        vm.set_block_timestamp(1_001 + RENOUNCE_WINDOW);
+        assert!(matches!(
+            contract.confirm_renounce(),
+            Err(IssuerRegistryError::RenounceWindowExpired(_))
+        ));
+        assert_eq!(contract.get_owner(), owner);
+
+        // Initiating again opens a fresh window
+        assert!(contract.initiate_renounce().is_ok());
+        assert!(contract.confirm_renounce().is_ok());
+        assert_eq!(contract.get_owner(), Address::ZERO);
+    }
+
+    #[test]
+    fn test_renounce_access_and_handoff() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.initiate_renounce(),
+            Err(IssuerRegistryError::Unauthorized(_))
+        ));
+
+        // A renounce started by the old owner does not carry over to the new one
+        vm.set_sender(owner);
+        assert!(contract.initiate_renounce().is_ok());
+        assert!(contract.transfer_ownership(alice).is_ok());
+        vm.set_sender(alice);
+        assert!(contract.accept_ownership().is_ok());
+        assert!(matches!(
+            contract.confirm_renounce(),
+            Err(IssuerRegistryError::RenounceNotInitiated(_))
+        ));
+        assert_eq!(contract.get_owner(), alice);
+    }
 }
@@ -0,0 +1,420 @@
+//!
+//! # Reputation Aggregator Contract
+//! Sums an account's reputation across a set of staking pools.
+//!
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use stylus_sdk::{alloy_sol_types::sol, prelude::*, stylus_core::calls::context::Call};
+
+/// Upper bound on tracked pools so `total_reputation_of` stays cheap
+const MAX_POOLS: usize = 20;
+
+sol! {
+    // Errors
+    error Unauthorized();
+    error AddressZeroNotAllowed();
+    error PoolAlreadyAdded();
+    error PoolNotFound();
+    error TooManyPools();
+
+    // Events
+    event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+    event PoolAdded(address indexed pool);
+    event PoolRemoved(address indexed pool);
+}
+
+sol! {
+    interface IReputationStaking {
+        function reputationOf(address account) external view returns (uint256);
+    }
+}
+
+#[derive(SolidityError)]
+pub enum ReputationAggregatorError {
+    Unauthorized(Unauthorized),
+    AddressZeroNotAllowed(AddressZeroNotAllowed),
+    PoolAlreadyAdded(PoolAlreadyAdded),
+    PoolNotFound(PoolNotFound),
+    TooManyPools(TooManyPools),
+}
+
+sol_storage! {
+    #[entrypoint]
+    pub struct ReputationAggregator {
+        address owner;
+        /// Staking pools whose reputation is summed
+        address[] pools;
+        /// Whether an address is currently a tracked pool
+        mapping(address => bool) is_pool;
+        /// Position of each tracked pool in `pools`
+        mapping(address => uint256) pool_index;
+        /// Reserved slots so future fields can be appended without shifting layout.
+        /// New fields go above this line; shrink the gap by the slots they use.
+        uint256[46] __gap;
+    }
+}
+
+impl ReputationAggregator {
+    /// Internal function to restrict a call to the owner
+    fn only_owner(&self) -> Result<(), ReputationAggregatorError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(ReputationAggregatorError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal function to read an account's reputation from one pool.
+    /// A failed or malformed call counts as zero reputation.
+    fn pool_reputation(&self, pool: Address, account: Address) -> U256 {
+        let calldata = IReputationStaking::reputationOfCall { account }.abi_encode();
+        let Ok(returned) = self.vm().static_call(&Call::new(), pool, &calldata) else {
+            return U256::ZERO;
+        };
+        IReputationStaking::reputationOfCall::abi_decode_returns(&returned, true)
+            .map(|reputation| reputation._0)
+            .unwrap_or(U256::ZERO)
+    }
+}
+
+#[public]
+impl ReputationAggregator {
+    #[constructor]
+    fn constructor(&mut self) -> Result<(), ReputationAggregatorError> {
+        let owner = self.vm().tx_origin();
+
+        if owner.is_zero() {
+            return Err(ReputationAggregatorError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.owner.set(owner);
+
+        log(
+            self.vm(),
+            OwnershipTransferred {
+                previous_owner: Address::ZERO,
+                new_owner: owner,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn add_pool(&mut self, pool: Address) -> Result<(), ReputationAggregatorError> {
+        self.only_owner()?;
+
+        if pool.is_zero() {
+            return Err(ReputationAggregatorError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        if self.is_pool.get(pool) {
+            return Err(ReputationAggregatorError::PoolAlreadyAdded(
+                PoolAlreadyAdded {},
+            ));
+        }
+
+        if self.pools.len() >= MAX_POOLS {
+            return Err(ReputationAggregatorError::TooManyPools(TooManyPools {}));
+        }
+
+        self.pool_index.insert(pool, U256::from(self.pools.len()));
+        self.pools.push(pool);
+        self.is_pool.insert(pool, true);
+
+        log(self.vm(), PoolAdded { pool });
+        Ok(())
+    }
+
+    /// Stop tracking a pool. The last pool takes its position, so order is not preserved.
+    fn remove_pool(&mut self, pool: Address) -> Result<(), ReputationAggregatorError> {
+        self.only_owner()?;
+
+        if !self.is_pool.get(pool) {
+            return Err(ReputationAggregatorError::PoolNotFound(PoolNotFound {}));
+        }
+
+        let index = self.pool_index.get(pool);
+        let last_index = self.pools.len() - 1;
+        if let Some(last) = self.pools.get(last_index) {
+            if let Some(mut slot) = self.pools.setter(index) {
+                slot.set(last);
+            }
+            self.pool_index.insert(last, index);
+        }
+
+        self.pools.pop();
+        self.pool_index.delete(pool);
+        self.is_pool.insert(pool, false);
+
+        log(self.vm(), PoolRemoved { pool });
+        Ok(())
+    }
+
+    /// Sum of `account`'s reputation across every tracked pool
+    fn total_reputation_of(&self, account: Address) -> U256 {
+        let mut total = U256::ZERO;
+
+        for i in 0..self.pools.len() {
+            if let Some(pool) = self.pools.get(i) {
+                total = total.saturating_add(self.pool_reputation(pool, account));
+            }
+        }
+
+        total
+    }
+
+    fn get_pools(&self) -> Vec<Address> {
+        let mut result = Vec::new();
+
+        for i in 0..self.pools.len() {
+            if let Some(pool) = self.pools.get(i) {
+                result.push(pool);
+            }
+        }
+
+        result
+    }
+
+    fn is_pool(&self, pool: Address) -> bool {
+        self.is_pool.get(pool)
+    }
+
+    fn get_owner(&self) -> Address {
+        self.owner.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, B256};
+    use stylus_sdk::testing::*;
+
+    /// First slot past the aggregator layout (end of the gap); adding fields must not move it
+    const AGGREGATOR_STORAGE_END: u64 = 50;
+
+    fn setup_contract() -> (TestVM, ReputationAggregator) {
+        let vm = TestVM::default();
+        let mut contract = ReputationAggregator::from(&vm);
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        let result = contract.constructor();
+        assert!(result.is_ok());
+
+        (vm, contract)
+    }
+
+    fn mock_reputation(vm: &TestVM, pool: Address, account: Address, reputation: U256) {
+        vm.mock_static_call(
+            pool,
+            IReputationStaking::reputationOfCall { account }.abi_encode(),
+            Ok(IReputationStaking::reputationOfCall::abi_encode_returns(&(
+                reputation,
+            ))),
+        );
+    }
+
+    #[test]
+    fn test_constructor_success() {
+        let (_vm, contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        assert_eq!(contract.get_owner(), owner);
+        assert!(contract.get_pools().is_empty());
+    }
+
+    #[test]
+    fn test_constructor_with_zero_address() {
+        let vm = TestVM::default();
+        let mut contract = ReputationAggregator::from(&vm);
+
+        vm.set_sender(Address::ZERO);
+        let result = contract.constructor();
+        assert!(matches!(
+            result,
+            Err(ReputationAggregatorError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_total_reputation_across_pools() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let pool_a = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let pool_b = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        mock_reputation(&vm, pool_a, alice, U256::from(40));
+        mock_reputation(&vm, pool_b, alice, U256::from(2));
+
+        // No pools means no reputation
+        assert_eq!(contract.total_reputation_of(alice), U256::ZERO);
+
+        vm.set_sender(owner);
+        assert!(contract.add_pool(pool_a).is_ok());
+        assert_eq!(contract.total_reputation_of(alice), U256::from(40));
+
+        assert!(contract.add_pool(pool_b).is_ok());
+        assert_eq!(contract.get_pools(), vec![pool_a, pool_b]);
+        assert_eq!(contract.total_reputation_of(alice), U256::from(42));
+
+        // Removing a pool drops its contribution
+        assert!(contract.remove_pool(pool_a).is_ok());
+        assert_eq!(contract.get_pools(), vec![pool_b]);
+        assert!(!contract.is_pool(pool_a));
+        assert_eq!(contract.total_reputation_of(alice), U256::from(2));
+    }
+
+    #[test]
+    fn test_unreachable_pool_counts_as_zero() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let pool_a = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let pool_b = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        mock_reputation(&vm, pool_a, alice, U256::from(7));
+
+        vm.set_sender(owner);
+        assert!(contract.add_pool(pool_a).is_ok());
+        assert!(contract.add_pool(pool_b).is_ok());
+        assert_eq!(contract.total_reputation_of(alice), U256::from(7));
+    }
+
+    #[test]
+    fn test_total_reputation_saturates() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let pool_a = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let pool_b = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        mock_reputation(&vm, pool_a, alice, U256::MAX);
+        mock_reputation(&vm, pool_b, alice, U256::from(1));
+
+        vm.set_sender(owner);
+        assert!(contract.add_pool(pool_a).is_ok());
+        assert!(contract.add_pool(pool_b).is_ok());
+        assert_eq!(contract.total_reputation_of(alice), U256::MAX);
+    }
+
+    #[test]
+    fn test_add_pool_errors() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let pool = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.add_pool(pool),
+            Err(ReputationAggregatorError::Unauthorized(_))
+        ));
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.add_pool(Address::ZERO),
+            Err(ReputationAggregatorError::AddressZeroNotAllowed(_))
+        ));
+        assert!(contract.add_pool(pool).is_ok());
+        assert!(matches!(
+            contract.add_pool(pool),
+            Err(ReputationAggregatorError::PoolAlreadyAdded(_))
+        ));
+    }
+
+    #[test]
+    fn test_add_pool_cap() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        for i in 0..MAX_POOLS {
+            assert!(contract
+                .add_pool(Address::with_last_byte(i as u8 + 1))
+                .is_ok());
+        }
+        assert!(matches!(
+            contract.add_pool(Address::with_last_byte(0xff)),
+            Err(ReputationAggregatorError::TooManyPools(_))
+        ));
+    }
+
+    #[test]
+    fn test_remove_pool_errors() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let pool = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.remove_pool(pool),
+            Err(ReputationAggregatorError::PoolNotFound(_))
+        ));
+        assert!(contract.add_pool(pool).is_ok());
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.remove_pool(pool),
+            Err(ReputationAggregatorError::Unauthorized(_))
+        ));
+        assert!(contract.is_pool(pool));
+    }
+
+    #[test]
+    fn test_remove_middle_pool_swaps_last() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let pools = [
+            Address::with_last_byte(1),
+            Address::with_last_byte(2),
+            Address::with_last_byte(3),
+        ];
+
+        vm.set_sender(owner);
+        for pool in pools {
+            assert!(contract.add_pool(pool).is_ok());
+        }
+        assert!(contract.remove_pool(pools[1]).is_ok());
+        assert_eq!(contract.get_pools(), vec![pools[0], pools[2]]);
+
+        // A removed pool can be added back at the end
+        assert!(contract.add_pool(pools[1]).is_ok());
+        assert_eq!(contract.get_pools(), vec![pools[0], pools[2], pools[1]]);
+        assert!(contract.remove_pool(pools[2]).is_ok());
+        assert_eq!(contract.get_pools(), vec![pools[0], pools[1]]);
+    }
+
+    #[test]
+    fn test_storage_layout_with_gap() {
+        let (vm, mut contract) = setup_contract();
+
+        for i in 0..contract.__gap.len() {
+            assert_eq!(contract.__gap.get(i), Some(U256::ZERO));
+        }
+
+        // The last gap slot sits right before the fixed end of the layout
+        let sentinel = U256::from(0xe7405);
+        let last = contract.__gap.len() - 1;
+        if let Some(mut slot) = contract.__gap.setter(last) {
+            slot.set(sentinel);
+        }
+        assert_eq!(
+            vm.get_storage(U256::from(AGGREGATOR_STORAGE_END - 1)),
+            B256::from(sentinel)
+        );
+    }
+}
@@ -0,0 +1,449 @@
+//!
+//! # Reputation Gate Contract
+//! Answers "does this account meet the required reputation tier?" for other dApps.
+//!
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use stylus_sdk::{alloy_sol_types::sol, prelude::*, stylus_core::calls::context::Call};
+
+/// Upper bound on configured tiers so `tier_of` stays cheap
+const MAX_TIERS: usize = 10;
+
+sol! {
+    // Errors
+    error Unauthorized();
+    error AddressZeroNotAllowed();
+    error ThresholdsNotAscending();
+    error TooManyTiers();
+    error TierOutOfRange(uint256 tier, uint256 max);
+
+    // Events
+    event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+    event ReputationStakingUpdated(address indexed reputation_staking);
+    event TiersUpdated(uint256[] thresholds);
+    event RequiredTierUpdated(uint256 tier);
+}
+
+sol! {
+    interface IReputationStaking {
+        function reputationOf(address account) external view returns (uint256);
+    }
+}
+
+#[derive(SolidityError)]
+pub enum ReputationGateError {
+    Unauthorized(Unauthorized),
+    AddressZeroNotAllowed(AddressZeroNotAllowed),
+    ThresholdsNotAscending(ThresholdsNotAscending),
+    TooManyTiers(TooManyTiers),
+    TierOutOfRange(TierOutOfRange),
+}
+
+sol_storage! {
+    #[entrypoint]
+    pub struct ReputationGate {
+        address owner;
+        /// Staking contract queried for reputation
+        address reputation_staking;
+        /// Minimum reputation for tiers 1..=n, strictly ascending; tier 0 needs nothing
+        uint256[] tier_thresholds;
+        /// Tier an account must reach for `check_access`
+        uint256 required_tier;
+        /// Reserved slots so future fields can be appended without shifting layout.
+        /// New fields go above this line; shrink the gap by the slots they use.
+        uint256[46] __gap;
+    }
+}
+
+impl ReputationGate {
+    /// Internal function to restrict a call to the owner
+    fn only_owner(&self) -> Result<(), ReputationGateError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(ReputationGateError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal function to read an account's reputation from the staking contract.
+    /// A failed or malformed call counts as zero reputation.
+    fn staking_reputation(&self, account: Address) -> U256 {
+        let calldata = IReputationStaking::reputationOfCall { account }.abi_encode();
+        let staking = self.reputation_staking.get();
+        let Ok(returned) = self.vm().static_call(&Call::new(), staking, &calldata) else {
+            return U256::ZERO;
+        };
+        IReputationStaking::reputationOfCall::abi_decode_returns(&returned, true)
+            .map(|reputation| reputation._0)
+            .unwrap_or(U256::ZERO)
+    }
+
+    /// Internal function to map a reputation to the highest tier it reaches
+    fn tier_for(&self, reputation: U256) -> U256 {
+        let mut tier = 0;
+        for i in 0..self.tier_thresholds.len() {
+            match self.tier_thresholds.get(i) {
+                Some(threshold) if reputation >= threshold => tier = i + 1,
+                _ => break,
+            }
+        }
+        U256::from(tier)
+    }
+
+    fn tier_out_of_range(tier: U256, max: usize) -> ReputationGateError {
+        ReputationGateError::TierOutOfRange(TierOutOfRange {
+            tier,
+            max: U256::from(max),
+        })
+    }
+}
+
+#[public]
+impl ReputationGate {
+    #[constructor]
+    fn constructor(&mut self, reputation_staking: Address) -> Result<(), ReputationGateError> {
+        let owner = self.vm().tx_origin();
+
+        if owner.is_zero() || reputation_staking.is_zero() {
+            return Err(ReputationGateError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.owner.set(owner);
+        self.reputation_staking.set(reputation_staking);
+
+        log(
+            self.vm(),
+            OwnershipTransferred {
+                previous_owner: Address::ZERO,
+                new_owner: owner,
+            },
+        );
+        log(self.vm(), ReputationStakingUpdated { reputation_staking });
+
+        Ok(())
+    }
+
+    fn set_reputation_staking(
+        &mut self,
+        reputation_staking: Address,
+    ) -> Result<(), ReputationGateError> {
+        self.only_owner()?;
+
+        if reputation_staking.is_zero() {
+            return Err(ReputationGateError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.reputation_staking.set(reputation_staking);
+
+        log(self.vm(), ReputationStakingUpdated { reputation_staking });
+        Ok(())
+    }
+
+    /// Replace the tier thresholds. The required tier must still exist afterwards.
+    fn set_tiers(&mut self, thresholds: Vec<U256>) -> Result<(), ReputationGateError> {
+        self.only_owner()?;
+
+        if thresholds.len() > MAX_TIERS {
+            return Err(ReputationGateError::TooManyTiers(TooManyTiers {}));
+        }
+        if thresholds.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(ReputationGateError::ThresholdsNotAscending(
+                ThresholdsNotAscending {},
+            ));
+        }
+        let required_tier = self.required_tier.get();
+        if required_tier > U256::from(thresholds.len()) {
+            return Err(Self::tier_out_of_range(required_tier, thresholds.len()));
+        }
+
+        while !self.tier_thresholds.is_empty() {
+            self.tier_thresholds.pop();
+        }
+        for threshold in thresholds.iter() {
+            self.tier_thresholds.push(*threshold);
+        }
+
+        log(self.vm(), TiersUpdated { thresholds });
+        Ok(())
+    }
+
+    /// Tier `check_access` requires; zero lets every account through
+    fn set_required_tier(&mut self, tier: U256) -> Result<(), ReputationGateError> {
+        self.only_owner()?;
+
+        let tiers = self.tier_thresholds.len();
+        if tier > U256::from(tiers) {
+            return Err(Self::tier_out_of_range(tier, tiers));
+        }
+
+        self.required_tier.set(tier);
+
+        log(self.vm(), RequiredTierUpdated { tier });
+        Ok(())
+    }
+
+    /// Whether `account` has reached the required tier
+    fn check_access(&self, account: Address) -> bool {
+        self.tier_of(account) >= self.required_tier.get()
+    }
+
+    /// Highest tier whose threshold `account`'s reputation meets
+    fn tier_of(&self, account: Address) -> U256 {
+        self.tier_for(self.staking_reputation(account))
+    }
+
+    fn required_tier(&self) -> U256 {
+        self.required_tier.get()
+    }
+
+    fn get_tier_thresholds(&self) -> Vec<U256> {
+        let mut result = Vec::new();
+
+        for i in 0..self.tier_thresholds.len() {
+            if let Some(threshold) = self.tier_thresholds.get(i) {
+                result.push(threshold);
+            }
+        }
+
+        result
+    }
+
+    fn get_reputation_staking(&self) -> Address {
+        self.reputation_staking.get()
+    }
+
+    fn get_owner(&self) -> Address {
+        self.owner.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, B256};
+    use stylus_sdk::testing::*;
+
+    /// First slot past the gate layout (end of the gap); adding fields must not move it
+    const GATE_STORAGE_END: u64 = 50;
+
+    fn setup_contract() -> (TestVM, ReputationGate) {
+        let vm = TestVM::default();
+        let mut contract = ReputationGate::from(&vm);
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(owner);
+        let result = contract.constructor(staking);
+        assert!(result.is_ok());
+
+        (vm, contract)
+    }
+
+    fn mock_reputation(vm: &TestVM, staking: Address, account: Address, reputation: U256) {
+        vm.mock_static_call(
+            staking,
+            IReputationStaking::reputationOfCall { account }.abi_encode(),
+            Ok(IReputationStaking::reputationOfCall::abi_encode_returns(&(
+                reputation,
+            ))),
+        );
+    }
+
+    /// Bronze/silver/gold at 10/100/1000 reputation
+    fn configure_tiers(vm: &TestVM, contract: &mut ReputationGate) {
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        let thresholds = vec![U256::from(10), U256::from(100), U256::from(1000)];
+        assert!(contract.set_tiers(thresholds).is_ok());
+    }
+
+    #[test]
+    fn test_constructor_success() {
+        let (_vm, contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        assert_eq!(contract.get_owner(), owner);
+        assert_eq!(contract.get_reputation_staking(), staking);
+        assert_eq!(contract.required_tier(), U256::ZERO);
+        assert!(contract.get_tier_thresholds().is_empty());
+    }
+
+    #[test]
+    fn test_constructor_with_zero_address() {
+        let vm = TestVM::default();
+        let mut contract = ReputationGate::from(&vm);
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.constructor(Address::ZERO),
+            Err(ReputationGateError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_access_pass_and_fail() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let staking = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let carol = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+
+        mock_reputation(&vm, staking, alice, U256::from(100));
+        mock_reputation(&vm, staking, bob, U256::from(99));
+        mock_reputation(&vm, staking, carol, U256::from(5_000));
+
+        configure_tiers(&vm, &mut contract);
+        assert_eq!(contract.tier_of(alice), U256::from(2));
+        assert_eq!(contract.tier_of(bob), U256::from(1));
+        assert_eq!(contract.tier_of(carol), U256::from(3));
+
+        // Tier 0 is open to everyone
+        assert!(contract.check_access(bob));
+
+        vm.set_sender(owner);
+        assert!(contract.set_required_tier(U256::from(2)).is_ok());
+        assert_eq!(contract.required_tier(), U256::from(2));
+        assert!(contract.check_access(alice));
+        assert!(!contract.check_access(bob));
+        assert!(contract.check_access(carol));
+    }
+
+    #[test]
+    fn test_unreachable_staking_denies_access() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        configure_tiers(&vm, &mut contract);
+        vm.set_sender(owner);
+        assert!(contract.set_required_tier(U256::from(1)).is_ok());
+
+        // No mocked response behaves like a reverting staking contract
+        assert_eq!(contract.tier_of(alice), U256::ZERO);
+        assert!(!contract.check_access(alice));
+    }
+
+    #[test]
+    fn test_set_reputation_staking() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let new_staking = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        mock_reputation(&vm, new_staking, alice, U256::from(1000));
+        configure_tiers(&vm, &mut contract);
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.set_reputation_staking(new_staking),
+            Err(ReputationGateError::Unauthorized(_))
+        ));
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.set_reputation_staking(Address::ZERO),
+            Err(ReputationGateError::AddressZeroNotAllowed(_))
+        ));
+        assert!(contract.set_reputation_staking(new_staking).is_ok());
+        assert_eq!(contract.get_reputation_staking(), new_staking);
+        assert_eq!(contract.tier_of(alice), U256::from(3));
+    }
+
+    #[test]
+    fn test_set_tiers_validation() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.set_tiers(vec![U256::from(1)]),
+            Err(ReputationGateError::Unauthorized(_))
+        ));
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.set_tiers(vec![U256::from(10), U256::from(10)]),
+            Err(ReputationGateError::ThresholdsNotAscending(_))
+        ));
+        assert!(matches!(
+            contract.set_tiers(vec![U256::from(10), U256::from(5)]),
+            Err(ReputationGateError::ThresholdsNotAscending(_))
+        ));
+        let too_many = (1..=MAX_TIERS as u64 + 1).map(U256::from).collect();
+        assert!(matches!(
+            contract.set_tiers(too_many),
+            Err(ReputationGateError::TooManyTiers(_))
+        ));
+
+        // Replacing tiers overwrites the old list entirely
+        configure_tiers(&vm, &mut contract);
+        assert!(contract.set_tiers(vec![U256::from(7)]).is_ok());
+        assert_eq!(contract.get_tier_thresholds(), vec![U256::from(7)]);
+    }
+
+    #[test]
+    fn test_required_tier_must_exist() {
+        let (vm, mut contract) = setup_contract();
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            contract.set_required_tier(U256::from(1)),
+            Err(ReputationGateError::TierOutOfRange(TierOutOfRange { tier, max }))
+                if tier == U256::from(1) && max == U256::ZERO
+        ));
+
+        configure_tiers(&vm, &mut contract);
+        assert!(contract.set_required_tier(U256::from(3)).is_ok());
+
+        // Shrinking the tier list below the required tier is refused
+        assert!(matches!(
+            contract.set_tiers(vec![U256::from(10)]),
+            Err(ReputationGateError::TierOutOfRange(_))
+        ));
+        assert_eq!(contract.get_tier_thresholds().len(), 3);
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            contract.set_required_tier(U256::ZERO),
+            Err(ReputationGateError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_storage_layout_with_gap() {
+        let (vm, mut contract) = setup_contract();
+
+        for i in 0..contract.__gap.len() {
+            assert_eq!(contract.__gap.get(i), Some(U256::ZERO));
+        }
+
+        // The last gap slot sits right before the fixed end of the layout
+        let sentinel = U256::from(0xe7405);
+        let last = contract.__gap.len() - 1;
+        if let Some(mut slot) = contract.__gap.setter(last) {
+            slot.set(sentinel);
+        }
+        assert_eq!(
+            vm.get_storage(U256::from(GATE_STORAGE_END - 1)),
+            B256::from(sentinel)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Storage-write benchmarks for the mint paths.
+//!
+//! TestVM does not meter gas, so cost is approximated by the number of storage slots an
+//! operation changes plus the number of logs it emits. SSTOREs and LOGs dominate the
+//! real cost of these paths, so a jump in either is a gas regression.
+
+use super::*;
+use alloy_primitives::address;
+use stylus_sdk::testing::*;
+
+/// Slots changed and logs emitted by one operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Cost {
+    slots: usize,
+    logs: usize,
+}
+
+impl Cost {
+    fn add(self, other: Cost) -> Cost {
+        Cost {
+            slots: self.slots + other.slots,
+            logs: self.logs + other.logs,
+        }
+    }
+}
+
+fn measure<T>(vm: &TestVM, op: impl FnOnce() -> T) -> (T, Cost) {
+    let before = vm.snapshot();
+    let result = op();
+    let after = vm.snapshot();
+
+    let slots = after
+        .storage
+        .iter()
+        .filter(|(key, value)| before.storage.get(*key) != Some(*value))
+        .count();
+    let logs = after.emitted_logs.len() - before.emitted_logs.len();
+
+    (result, Cost { slots, logs })
+}
+
+fn setup() -> (TestVM, SBT) {
+    let vm = TestVM::default();
+    let mut sbt = SBT::from(&vm);
+    let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+    vm.set_sender(issuer);
+    let result = sbt.constructor(
+        "Bench SBT".to_string(),
+        "BSBT".to_string(),
+        issuer,
+        Address::ZERO,
+        0,
+        0,
+        String::new(),
+    );
+    assert!(result.is_ok());
+
+    (vm, sbt)
+}
+
+fn recipients(n: usize) -> Vec<Address> {
+    (0..n)
+        .map(|i| Address::with_last_byte(i as u8 + 1))
+        .collect()
+}
+
+#[test]
+fn bench_mint_to_one_baseline() {
+    let (vm, mut sbt) = setup();
+    let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+    let (result, cost) = measure(&vm, || sbt.mint_to_one(alice));
+    assert!(result.is_ok());
+
+    // entropy, owner, mint time, balance, next id, minted count; Transfer and Minted logs
+    assert!(cost.slots <= 6, "mint_to_one wrote {} slots", cost.slots);
+    assert_eq!(cost.logs, 2);
+}
+
+#[test]
+fn bench_mint_to_many_scales_linearly() {
+    for n in [1usize, 5, 20, 100] {
+        let (vm, mut sbt) = setup();
+
+        let (result, cost) = measure(&vm, || sbt.mint_to_many(recipients(n)));
+        assert!(result.is_ok());
+
+        // Four slots per fresh recipient plus the shared next id and minted count
+        assert!(
+            cost.slots <= 4 * n + 2,
+            "mint_to_many({}) wrote {} slots",
+            n,
+            cost.slots
+        );
+        assert_eq!(cost.logs, 2 * n);
+    }
+}
+
+#[test]
+fn bench_batch_cheaper_than_singles() {
+    for n in [2usize, 5, 20] {
+        let (vm, mut sbt) = setup();
+        let (result, batch) = measure(&vm, || sbt.mint_to_many(recipients(n)));
+        assert!(result.is_ok());
+
+        let (vm, mut sbt) = setup();
+        let mut singles = Cost { slots: 0, logs: 0 };
+        for recipient in recipients(n) {
+            let (result, cost) = measure(&vm, || sbt.mint_to_one(recipient));
+            assert!(result.is_ok());
+            singles = singles.add(cost);
+        }
+
+        assert!(
+            batch.slots < singles.slots,
+            "batch of {} wrote {} slots, singles wrote {}",
+            n,
+            batch.slots,
+            singles.slots
+        );
+        assert_eq!(batch.logs, singles.logs);
+    }
+}
@@ -1,23 +1,33 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt::Write;
-use stylus_sdk::alloy_primitives::FixedBytes;
+use stylus_sdk::alloy_primitives::{Address, FixedBytes};
 
 use crate::base64::base64_encode;
 
-const SVG_WIDTH: i32 = 1000;
-const SVG_HEIGHT: i32 = 1000;
-const BACKGROUND_COLOR: &str = "#0C0404";
-
-// Hexagon center
-const CENTER_X: i32 = 500;
-const CENTER_Y: i32 = 500;
-
-// Hexagon parameters
+pub const SVG_WIDTH: u32 = 1000;
+pub const SVG_HEIGHT: u32 = 1000;
+pub const BACKGROUND_COLOR: &str = "#0C0404";
+pub const DEFAULT_NAME: &str = "Ethos SBT";
+pub const DEFAULT_DESCRIPTION: &str = "Ethos SBT on Arbitrum";
+// Arbitrum One, Nova and Sepolia; the default description only names Arbitrum on these
+const ARBITRUM_CHAIN_IDS: &[u64] = &[42161, 42170, 421614];
+/// Largest SVG embedded in metadata. Today's art is well under 1KB; anything
+/// past 16KB is left out so tokenURI stays cheap enough for marketplaces to call.
+pub const MAX_SVG_BYTES: usize = 16 * 1024;
+
+// Hexagon parameters (relative to the default 1000px canvas)
+const INNER_OFFSET: i32 = 40;
 const MIN_SIZE: usize = 150;
 const MAX_SIZE: usize = 250;
 const MIN_STROKE_WIDTH: usize = 12;
 const MAX_STROKE_WIDTH: usize = 24;
 
+// Rarity weights; changing these changes every published score
+const SIZE_RARITY_WEIGHT: u32 = 2;
+const STROKE_RARITY_WEIGHT: u32 = 10;
+const NO_INNER_RARITY_BONUS: u32 = 40;
+
 // Color palette
 const COLORS: &[&str] = &[
     "#1BA3E8", // Arbitrum blue
@@ -32,33 +42,206 @@ const COLORS: &[&str] = &[
     "#00D2D3", // Cyan
 ];
 
+/// Canvas settings for the generated artwork
+pub struct GeneratorConfig {
+    pub width: u32,
+    pub height: u32,
+    pub background: String,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        Self {
+            width: SVG_WIDTH,
+            height: SVG_HEIGHT,
+            background: String::from(BACKGROUND_COLOR),
+        }
+    }
+}
+
+impl GeneratorConfig {
+    /// Accepts `#RGB` or `#RRGGBB` so the value can be embedded in the SVG safely
+    pub fn is_valid_background(background: &str) -> bool {
+        let bytes = background.as_bytes();
+        (bytes.len() == 4 || bytes.len() == 7)
+            && bytes[0] == b'#'
+            && bytes[1..].iter().all(u8::is_ascii_hexdigit)
+    }
+}
+
 pub struct SBTGenerator {
     seed: FixedBytes<32>,
+    config: GeneratorConfig,
+    owner: Option<Address>,
+    name: String,
+    description: Option<String>,
+    chain_id: Option<u64>,
 }
 
 impl SBTGenerator {
     pub fn new(seed: FixedBytes<32>) -> Self {
-        Self { seed }
+        Self::with_config(seed, GeneratorConfig::default())
+    }
+
+    pub fn with_config(seed: FixedBytes<32>, config: GeneratorConfig) -> Self {
+        Self {
+            seed,
+            config,
+            owner: None,
+            name: String::from(DEFAULT_NAME),
+            description: None,
+            chain_id: None,
+        }
+    }
+
+    // Use the collection's name in the metadata instead of the default
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = String::from(name);
+        self
+    }
+
+    // Describe the collection in the metadata instead of the default text
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(String::from(description));
+        self
+    }
+
+    // Record the chain the token lives on as a chainId field. Off Arbitrum the
+    // default description names the chain id instead.
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    fn description(&self) -> String {
+        match (&self.description, self.chain_id) {
+            (Some(description), _) => description.clone(),
+            (None, Some(chain_id)) if !ARBITRUM_CHAIN_IDS.contains(&chain_id) => {
+                format!("Ethos SBT on chain {}", chain_id)
+            }
+            _ => String::from(DEFAULT_DESCRIPTION),
+        }
+    }
+
+    // Take the primary color from the owner's address so all of a holder's tokens match.
+    // Shape and size still come from the seed. If the token ever moves to another
+    // address (unlock, recovery, migration), its color changes with it.
+    pub fn with_owner(mut self, owner: Address) -> Self {
+        self.owner = Some(owner);
+        self
     }
 
     // Main function that generates the complete metadata
     pub fn metadata(&self) -> String {
-        let svg = self.svg();
-        let base64_svg = base64_encode(&svg);
+        self.encode_metadata(false)
+    }
 
-        let metadata = format!(
-            r#"{{"name":"Ethos SBT","description":"Ethos SBT on Arbitrum","image":"data:image/svg+xml;base64,{}"}}"#,
-            base64_svg
-        );
-        let base64_metadata = base64_encode(&metadata);
+    // Same metadata, tagged with a Frozen status attribute
+    pub fn frozen_metadata(&self) -> String {
+        self.encode_metadata(true)
+    }
 
+    fn encode_metadata(&self, frozen: bool) -> String {
+        let base64_metadata = base64_encode(&self.metadata_json(frozen));
         format!(r#"data:application/json;base64,{}"#, base64_metadata)
     }
 
+    // Deterministic score from the seed-driven traits; higher is rarer.
+    // Sizes and stroke widths far from the middle of their range score higher,
+    // as does a hexagon too small for the inner ring. Color is left out because
+    // it follows the owner, not the token. Ranges from 0 to 200.
+    pub fn rarity_score(&self) -> u32 {
+        let mid_size = ((MIN_SIZE + MAX_SIZE) / 2) as u32;
+        let mid_stroke = ((MIN_STROKE_WIDTH + MAX_STROKE_WIDTH) / 2) as u32;
+        let size = self.map_byte(self.seed[0], MIN_SIZE, MAX_SIZE) as u32;
+        let stroke = self.map_byte(self.seed[1], MIN_STROKE_WIDTH, MAX_STROKE_WIDTH) as u32;
+
+        let mut score = size.abs_diff(mid_size) * SIZE_RARITY_WEIGHT
+            + stroke.abs_diff(mid_stroke) * STROKE_RARITY_WEIGHT;
+        if !Self::has_inner_hexagon(size as i32) {
+            score += NO_INNER_RARITY_BONUS;
+        }
+        score
+    }
+
+    // Palette index picked for a token with no owner color, from the third
+    // seed byte. 256 does not divide evenly by the 10 colors, so the first six
+    // come up 26 times in 256 and the rest 25; that is within about 4% of
+    // uniform, which `color_distribution` can confirm over a batch of seeds.
+    pub fn color_index_for_seed(seed: FixedBytes<32>) -> usize {
+        (seed[2] as usize) % COLORS.len()
+    }
+
+    // How many of the given seeds land on each palette color, by index.
+    // Off-chain helper for checking a palette for skew before using it.
+    pub fn color_distribution<I>(seeds: I) -> Vec<usize>
+    where
+        I: IntoIterator<Item = FixedBytes<32>>,
+    {
+        let mut counts = vec![0; COLORS.len()];
+        for seed in seeds {
+            counts[Self::color_index_for_seed(seed)] += 1;
+        }
+        counts
+    }
+
+    fn has_inner_hexagon(base_size: i32) -> bool {
+        base_size > 180
+    }
+
+    fn metadata_json(&self, frozen: bool) -> String {
+        self.metadata_json_capped(frozen, MAX_SVG_BYTES)
+    }
+
+    // Drops the image rather than failing when the SVG is over `max_svg_bytes`;
+    // the name, description and attributes are still valid metadata on their own.
+    fn metadata_json_capped(&self, frozen: bool, max_svg_bytes: usize) -> String {
+        let svg = self.svg();
+        let image = if svg.len() <= max_svg_bytes {
+            format!(
+                r#","image":"data:image/svg+xml;base64,{}""#,
+                base64_encode(&svg)
+            )
+        } else {
+            String::new()
+        };
+        let status = if frozen {
+            r#",{"trait_type":"Status","value":"Frozen"}"#
+        } else {
+            ""
+        };
+        let chain_id = match self.chain_id {
+            Some(chain_id) => format!(r#","chainId":{}"#, chain_id),
+            None => String::new(),
+        };
+        let attributes = format!(
+            r#","attributes":[{{"trait_type":"Rarity","display_type":"number","value":{}}}{}]"#,
+            self.rarity_score(),
+            status
+        );
+
+        format!(
+            r#"{{"name":"{}","description":"{}"{}{}{}}}"#,
+            escape_json(&self.name),
+            escape_json(&self.description()),
+            chain_id,
+            image,
+            attributes
+        )
+    }
+
     fn svg(&self) -> String {
-        let size = self.map_byte(self.seed[0], MIN_SIZE, MAX_SIZE) as i32;
-        let stroke_width = self.map_byte(self.seed[1], MIN_STROKE_WIDTH, MAX_STROKE_WIDTH) as i32;
-        let color_index = (self.seed[2] as usize) % COLORS.len();
+        let width = self.config.width as i32;
+        let height = self.config.height as i32;
+        let base_size = self.map_byte(self.seed[0], MIN_SIZE, MAX_SIZE) as i32;
+        let size = self.scale(base_size);
+        let stroke_width = self
+            .scale(self.map_byte(self.seed[1], MIN_STROKE_WIDTH, MAX_STROKE_WIDTH) as i32)
+            .max(1);
+        let color_index = match self.owner {
+            Some(owner) => owner.iter().map(|b| *b as usize).sum::<usize>() % COLORS.len(),
+            None => Self::color_index_for_seed(self.seed),
+        };
         let color = COLORS[color_index];
 
         let mut svg = String::new();
@@ -67,19 +250,19 @@ impl SBTGenerator {
         write!(
                 svg,
                 r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg">"#,
-                SVG_WIDTH, SVG_HEIGHT, SVG_WIDTH, SVG_HEIGHT
+                width, height, width, height
             ).unwrap();
 
         // Background
         write!(
             svg,
             r#"<rect width="100%" height="100%" fill="{}"/>"#,
-            BACKGROUND_COLOR
+            self.config.background
         )
         .unwrap();
 
         // Generate main hexagon path
-        let hexagon_path = self.generate_hexagon_path(CENTER_X, CENTER_Y, size);
+        let hexagon_path = self.generate_hexagon_path(width / 2, height / 2, size);
 
         // Main hexagon
         write!(
@@ -89,11 +272,11 @@ impl SBTGenerator {
             ).unwrap();
 
         // Optional: Add inner hexagon for more visual interest
-        if size > 180 {
+        if Self::has_inner_hexagon(base_size) {
             // Only add if main hexagon is large enough
-            let inner_size = size - 40;
+            let inner_size = size - self.scale(INNER_OFFSET);
             let inner_stroke = stroke_width / 2;
-            let inner_path = self.generate_hexagon_path(CENTER_X, CENTER_Y, inner_size);
+            let inner_path = self.generate_hexagon_path(width / 2, height / 2, inner_size);
 
             write!(
                     svg,
@@ -136,14 +319,35 @@ impl SBTGenerator {
         path
     }
 
+    // Scale a length from the default canvas to the configured one, using the shorter side
+    fn scale(&self, value: i32) -> i32 {
+        let side = self.config.width.min(self.config.height) as i32;
+        (value * side) / SVG_WIDTH as i32
+    }
+
     fn map_byte(&self, byte: u8, min: usize, max: usize) -> usize {
         min + ((byte as usize * (max - min)) / 255)
     }
 }
 
+// Escape a string for use inside a JSON string literal
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use stylus_sdk::alloy_primitives::keccak256;
 
     #[test]
     fn test_hexagon_generation() {
@@ -194,4 +398,330 @@ mod tests {
         assert!(!svg.contains(".0"));
         assert!(!svg.contains(".5"));
     }
+
+    #[test]
+    fn test_default_config_matches_constants() {
+        let seed = FixedBytes::<32>::from([200u8; 32]);
+        let generator = SBTGenerator::with_config(seed, GeneratorConfig::default());
+        let svg = generator.svg();
+
+        assert!(svg.starts_with(
+            r#"<svg width="1000" height="1000" viewBox="0 0 1000 1000" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+        assert!(svg.contains(r##"fill="#0C0404""##));
+    }
+
+    #[test]
+    fn test_custom_config_dimensions_and_background() {
+        let seed = FixedBytes::<32>::from([200u8; 32]);
+        let config = GeneratorConfig {
+            width: 400,
+            height: 300,
+            background: String::from("#ffffff"),
+        };
+        let generator = SBTGenerator::with_config(seed, config);
+        let svg = generator.svg();
+
+        assert!(svg.starts_with(
+            r#"<svg width="400" height="300" viewBox="0 0 400 300" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+        assert!(svg.contains(r##"fill="#ffffff""##));
+
+        // Hexagon is centered on the custom canvas and stays deterministic
+        let size = generator.scale(generator.map_byte(200, MIN_SIZE, MAX_SIZE) as i32);
+        let right_point = format!("M {} {}", 200 + size, 150);
+        assert!(svg.contains(&right_point));
+        assert_eq!(
+            svg,
+            SBTGenerator::with_config(
+                seed,
+                GeneratorConfig {
+                    width: 400,
+                    height: 300,
+                    background: String::from("#ffffff"),
+                }
+            )
+            .svg()
+        );
+    }
+
+    fn primary_color(svg: &str) -> &str {
+        let start = svg.find(r#"stroke=""#).unwrap() + r#"stroke=""#.len();
+        &svg[start..start + 7]
+    }
+
+    #[test]
+    fn test_owner_color_shared_across_tokens() {
+        let owner = Address::from([0x42u8; 20]);
+        let first = SBTGenerator::with_config(
+            FixedBytes::<32>::from([10u8; 32]),
+            GeneratorConfig::default(),
+        )
+        .with_owner(owner);
+        let second = SBTGenerator::with_config(
+            FixedBytes::<32>::from([240u8; 32]),
+            GeneratorConfig::default(),
+        )
+        .with_owner(owner);
+
+        let first_svg = first.svg();
+        let second_svg = second.svg();
+
+        // Same owner, same color; different seeds, different shapes
+        assert_eq!(primary_color(&first_svg), primary_color(&second_svg));
+        assert_ne!(first_svg, second_svg);
+
+        // Deterministic for the same inputs
+        assert_eq!(
+            first_svg,
+            SBTGenerator::with_config(
+                FixedBytes::<32>::from([10u8; 32]),
+                GeneratorConfig::default()
+            )
+            .with_owner(owner)
+            .svg()
+        );
+    }
+
+    #[test]
+    fn test_owner_color_differs_between_owners() {
+        let seed = FixedBytes::<32>::from([10u8; 32]);
+        let alice = SBTGenerator::with_config(seed, GeneratorConfig::default())
+            .with_owner(Address::from([0x01u8; 20]));
+        let bob = SBTGenerator::with_config(seed, GeneratorConfig::default())
+            .with_owner(Address::with_last_byte(0x01));
+
+        assert_ne!(primary_color(&alice.svg()), primary_color(&bob.svg()));
+    }
+
+    #[test]
+    fn test_frozen_metadata_differs() {
+        let seed = FixedBytes::<32>::from([7u8; 32]);
+        let generator = SBTGenerator::with_config(seed, GeneratorConfig::default());
+
+        let plain = generator.metadata();
+        let frozen = generator.frozen_metadata();
+        assert!(plain.starts_with("data:application/json;base64,"));
+        assert!(frozen.starts_with("data:application/json;base64,"));
+        assert_ne!(plain, frozen);
+    }
+
+    #[test]
+    fn test_metadata_uses_collection_name() {
+        let seed = FixedBytes::<32>::from([7u8; 32]);
+        let default = SBTGenerator::with_config(seed, GeneratorConfig::default());
+        assert!(default
+            .metadata_json(false)
+            .starts_with(r#"{"name":"Ethos SBT","#));
+
+        let named =
+            SBTGenerator::with_config(seed, GeneratorConfig::default()).with_name("Guild Badge");
+        assert!(named
+            .metadata_json(false)
+            .starts_with(r#"{"name":"Guild Badge","#));
+        assert_ne!(named.metadata(), default.metadata());
+    }
+
+    #[test]
+    fn test_metadata_uses_collection_description() {
+        let seed = FixedBytes::<32>::from([7u8; 32]);
+        let default = SBTGenerator::with_config(seed, GeneratorConfig::default());
+        assert!(default
+            .metadata_json(false)
+            .starts_with(r#"{"name":"Ethos SBT","description":"Ethos SBT on Arbitrum","#));
+
+        let described = SBTGenerator::with_config(seed, GeneratorConfig::default())
+            .with_description("Badges for \"core\" contributors");
+        assert!(described.metadata_json(false).starts_with(
+            r#"{"name":"Ethos SBT","description":"Badges for \"core\" contributors","#
+        ));
+        assert_ne!(described.metadata(), default.metadata());
+    }
+
+    #[test]
+    fn test_metadata_records_chain_id() {
+        let seed = FixedBytes::<32>::from([7u8; 32]);
+        let arbitrum = SBTGenerator::with_config(seed, GeneratorConfig::default())
+            .with_chain_id(42161)
+            .metadata_json(false);
+        assert!(arbitrum.starts_with(
+            r#"{"name":"Ethos SBT","description":"Ethos SBT on Arbitrum","chainId":42161,"image":"#
+        ));
+
+        // Elsewhere the default description stops claiming Arbitrum
+        let base = SBTGenerator::with_config(seed, GeneratorConfig::default())
+            .with_chain_id(8453)
+            .metadata_json(false);
+        assert!(base.starts_with(
+            r#"{"name":"Ethos SBT","description":"Ethos SBT on chain 8453","chainId":8453,"image":"#
+        ));
+
+        // A custom description is left alone
+        let described = SBTGenerator::with_config(seed, GeneratorConfig::default())
+            .with_description("Guild members")
+            .with_chain_id(8453)
+            .metadata_json(false);
+        assert!(described.starts_with(
+            r#"{"name":"Ethos SBT","description":"Guild members","chainId":8453,"image":"#
+        ));
+    }
+
+    #[test]
+    fn test_metadata_name_is_escaped() {
+        assert_eq!(escape_json(r#"a"b\c"#), r#"a\"b\\c"#);
+        assert_eq!(escape_json("line\nbreak"), r#"line\u000abreak"#);
+        assert_eq!(escape_json("Plain Name"), "Plain Name");
+    }
+
+    #[test]
+    fn test_rarity_score_known_seeds() {
+        // Middle of both ranges with an inner ring: the most common look
+        let common = SBTGenerator::with_config(
+            FixedBytes::<32>::from([128u8; 32]),
+            GeneratorConfig::default(),
+        );
+        // Smallest size and thinnest stroke, no inner ring
+        let rare = SBTGenerator::with_config(
+            FixedBytes::<32>::from([0u8; 32]),
+            GeneratorConfig::default(),
+        );
+
+        // size 200, stroke 18 -> 0
+        assert_eq!(common.rarity_score(), 0);
+        // size 150 (50 * 2) + stroke 12 (6 * 10) + no inner ring (40)
+        assert_eq!(rare.rarity_score(), 200);
+
+        // Reproducible, and independent of owner and canvas
+        let again = SBTGenerator::with_config(
+            FixedBytes::<32>::from([0u8; 32]),
+            GeneratorConfig {
+                width: 400,
+                height: 300,
+                background: String::from("#fff"),
+            },
+        )
+        .with_owner(Address::with_last_byte(9));
+        assert_eq!(again.rarity_score(), rare.rarity_score());
+    }
+
+    #[test]
+    fn test_metadata_includes_rarity() {
+        let generator = SBTGenerator::with_config(
+            FixedBytes::<32>::from([0u8; 32]),
+            GeneratorConfig::default(),
+        );
+
+        assert!(generator.metadata_json(false).ends_with(
+            r#","attributes":[{"trait_type":"Rarity","display_type":"number","value":200}]}"#
+        ));
+        assert!(generator.metadata_json(true).ends_with(
+            r#","attributes":[{"trait_type":"Rarity","display_type":"number","value":200},{"trait_type":"Status","value":"Frozen"}]}"#
+        ));
+    }
+
+    #[test]
+    fn test_color_index_matches_svg() {
+        let seed = FixedBytes::<32>::from([3u8; 32]);
+        let index = SBTGenerator::color_index_for_seed(seed);
+        let svg = SBTGenerator::with_config(seed, GeneratorConfig::default()).svg();
+
+        assert_eq!(index, 3);
+        assert_eq!(primary_color(&svg), COLORS[index]);
+    }
+
+    #[test]
+    fn test_color_distribution_roughly_uniform() {
+        let samples = 20_000u32;
+        let seeds = (0..samples).map(|i| keccak256(i.to_be_bytes()));
+        let counts = SBTGenerator::color_distribution(seeds);
+
+        assert_eq!(counts.len(), COLORS.len());
+        assert_eq!(counts.iter().sum::<usize>(), samples as usize);
+
+        // Every color within 10% of an even share
+        let expected = samples as usize / COLORS.len();
+        for count in counts {
+            assert!(count.abs_diff(expected) < expected / 10);
+        }
+    }
+
+    #[test]
+    fn test_oversized_svg_falls_back_to_minimal_metadata() {
+        let generator = SBTGenerator::with_config(
+            FixedBytes::<32>::from([0u8; 32]),
+            GeneratorConfig::default(),
+        );
+        assert!(generator.svg().len() <= MAX_SVG_BYTES);
+        assert!(generator
+            .metadata_json(false)
+            .contains(r#""image":"data:image/svg+xml;base64,"#));
+
+        // Any limit below the actual render drops the image and nothing else
+        let limit = generator.svg().len() - 1;
+        assert_eq!(
+            generator.metadata_json_capped(false, limit),
+            r#"{"name":"Ethos SBT","description":"Ethos SBT on Arbitrum","attributes":[{"trait_type":"Rarity","display_type":"number","value":200}]}"#
+        );
+        assert_eq!(
+            generator.metadata_json_capped(true, limit),
+            r#"{"name":"Ethos SBT","description":"Ethos SBT on Arbitrum","attributes":[{"trait_type":"Rarity","display_type":"number","value":200},{"trait_type":"Status","value":"Frozen"}]}"#
+        );
+    }
+
+    // Golden renders. A failure here means the artwork changed: check the new
+    // output by eye, then regenerate the fixture on purpose.
+    fn assert_snapshot(generator: SBTGenerator, expected: &str) {
+        assert_eq!(generator.svg(), expected.trim_end());
+    }
+
+    #[test]
+    fn test_svg_snapshot_without_inner_hexagon() {
+        // Smallest size, below the inner hexagon cutoff
+        assert_snapshot(
+            SBTGenerator::with_config(FixedBytes::from([0u8; 32]), GeneratorConfig::default()),
+            include_str!("../fixtures/svg/small_no_inner.svg"),
+        );
+    }
+
+    #[test]
+    fn test_svg_snapshot_with_inner_hexagon() {
+        assert_snapshot(
+            SBTGenerator::with_config(FixedBytes::from([255u8; 32]), GeneratorConfig::default()),
+            include_str!("../fixtures/svg/large_with_inner.svg"),
+        );
+    }
+
+    #[test]
+    fn test_svg_snapshot_owner_color() {
+        assert_snapshot(
+            SBTGenerator::with_config(FixedBytes::from([128u8; 32]), GeneratorConfig::default())
+                .with_owner(Address::from([0x42u8; 20])),
+            include_str!("../fixtures/svg/owner_color.svg"),
+        );
+    }
+
+    #[test]
+    fn test_svg_snapshot_custom_canvas() {
+        assert_snapshot(
+            SBTGenerator::with_config(
+                FixedBytes::from([200u8; 32]),
+                GeneratorConfig {
+                    width: 400,
+                    height: 300,
+                    background: String::from("#ffffff"),
+                },
+            ),
+            include_str!("../fixtures/svg/custom_canvas.svg"),
+        );
+    }
+
+    #[test]
+    fn test_background_validation() {
+        assert!(GeneratorConfig::is_valid_background("#0C0404"));
+        assert!(GeneratorConfig::is_valid_background("#fff"));
+        assert!(!GeneratorConfig::is_valid_background("0C0404"));
+        assert!(!GeneratorConfig::is_valid_background("#0C04"));
+        assert!(!GeneratorConfig::is_valid_background("#zzzzzz"));
+        assert!(!GeneratorConfig::is_valid_background("\"/><script>"));
+    }
 }
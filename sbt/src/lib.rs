@@ -7,20 +7,34 @@
 #![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
 
 mod base64;
-mod generator;
+#[cfg(test)]
+mod gas_bench;
+pub mod generator;
 
 #[macro_use]
 extern crate alloc;
 
-use alloc::{string::String, vec::Vec};
-use alloy_sol_types::SolValue;
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use alloy_sol_types::{SolCall, SolValue};
 use stylus_sdk::{
-    alloy_primitives::{Address, FixedBytes, U256},
+    abi::Bytes,
+    alloy_primitives::{Address, FixedBytes, U256, U32},
     alloy_sol_types::sol,
     crypto::keccak,
     prelude::*,
+    stylus_core::calls::context::Call,
 };
 
+/// Maximum number of entries accepted by batch views
+const MAX_BATCH_SIZE: usize = 100;
+
+/// Project-defined id for issuer-minted soulbound collections:
+/// `getIssuer() ^ mintToOne(address) ^ mintToMany(address[])`
+const IETHOS_SBT_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x3f, 0x32, 0xe9, 0x04]);
+
+/// Upper bound for the generated artwork's width and height
+const MAX_SVG_DIMENSION: u32 = 10_000;
+
 sol_storage! {
     #[entrypoint]
     pub struct SBT {
@@ -31,18 +45,86 @@ sol_storage! {
         mapping(uint256 => address) owners;
         mapping(address => uint256) balances;
         mapping(uint256 => bytes32) entropy;
+        address sbt_factory;
+        mapping(uint256 => bool) unlocked;
+        /// Artwork canvas chosen at construction; zero/empty fall back to the generator defaults
+        uint32 svg_width;
+        uint32 svg_height;
+        string svg_background;
+        /// Block timestamp at which each token was minted
+        mapping(uint256 => uint256) minted_at;
+        /// Tokens the issuer has temporarily suspended
+        mapping(uint256 => bool) frozen;
+        /// Tokens ever minted and ever burned; live supply is the difference
+        uint256 minted_count;
+        uint256 burned_count;
+        /// Hash of the issuer's secret for the next `mint_with_reveal`; zero when none is pending
+        bytes32 entropy_commitment;
+        /// Salt each recipient contributes to their next revealed seed
+        mapping(address => bytes32) mint_salts;
+        /// Number of `commit_entropy` calls so far, and its value when each salt was set
+        uint256 entropy_commit_count;
+        mapping(address => uint256) mint_salt_commits;
+        /// Metadata description set by the issuer; empty means the generator default
+        string description;
+        /// Reputation contract told about every burn; zero disables the hook
+        address burn_hook;
+        /// When set, `token_uri` answers unminted ids with `placeholder_uri` instead of reverting
+        bool placeholder_uri_enabled;
+        string placeholder_uri;
+        /// Reserved slots so future fields can be appended without shifting layout.
+        /// New fields go above this line; shrink the gap by the slots they use.
+        uint256[36] __gap;
     }
 }
 
 sol! {
     // Events
     event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+    event Minted(address indexed to, uint256 indexed tokenId, bytes32 seed);
+    // ERC-5192 events
+    event Locked(uint256 tokenId);
+    event Unlocked(uint256 tokenId);
+    event SBTInitialized(string name, string symbol, address indexed issuer);
+    event IssuerUpdated(address indexed previous_issuer, address indexed new_issuer);
+    event Frozen(uint256 tokenId);
+    event Thawed(uint256 tokenId);
+    event CollectionRenamed(string name, string symbol);
+    event EntropyCommitted(bytes32 commitment);
+    event BurnHookUpdated(address indexed hook);
+    // ERC-4906 events
+    event MetadataUpdate(uint256 _tokenId);
+    event BatchMetadataUpdate(uint256 _fromTokenId, uint256 _toTokenId);
     // Errors
     error NotTransferable();
     error Unauthorized();
-    error TokenNotExists();
+    error TokenNotExists(uint256 token_id);
     error ZeroAddress();
     error EmptyArray();
+    error BatchTooLarge(uint256 length, uint256 max);
+    error InvalidSvgConfig();
+    error IdSpaceExhausted();
+    error AlreadyInitialized();
+    error InvalidReceiver(address receiver);
+    error EmptyString();
+    error InvalidReveal();
+    error InvalidMintSalt(address recipient);
+    error BurnHookFailed(address hook);
+    error DuplicateRecipient(address recipient);
+}
+
+sol! {
+    interface ISBTFactory {
+        function defaultBaseUri() external view returns (string);
+    }
+
+    interface IReputationHook {
+        function onCredentialBurned(address holder) external;
+    }
+
+    interface IERC721Receiver {
+        function onERC721Received(address operator, address from, uint256 tokenId, bytes data) external returns (bytes4);
+    }
 }
 
 #[derive(SolidityError)]
@@ -52,42 +134,317 @@ pub enum SBTErrors {
     TokenNotExists(TokenNotExists),
     ZeroAddress(ZeroAddress),
     EmptyArray(EmptyArray),
+    BatchTooLarge(BatchTooLarge),
+    InvalidSvgConfig(InvalidSvgConfig),
+    IdSpaceExhausted(IdSpaceExhausted),
+    AlreadyInitialized(AlreadyInitialized),
+    InvalidReceiver(InvalidReceiver),
+    EmptyString(EmptyString),
+    InvalidReveal(InvalidReveal),
+    InvalidMintSalt(InvalidMintSalt),
+    BurnHookFailed(BurnHookFailed),
+    DuplicateRecipient(DuplicateRecipient),
 }
 
 impl SBT {
     /// Generate deterministic entropy for each token
     fn generate_entropy(&self, token_id: U256, recipient: Address) -> FixedBytes<32> {
-        let block_number = self.vm().block_number();
-        let msg_sender = self.vm().msg_sender();
-        let chain_id = self.vm().chain_id();
+        Self::entropy_from(
+            self.vm().block_number(),
+            self.vm().msg_sender(),
+            self.vm().chain_id(),
+            token_id,
+            recipient,
+        )
+    }
+
+    /// Pure hash behind `generate_entropy`; every input feeds the seed.
+    ///
+    /// The issuer picks the mint block, so it could grind `block_number` for a preferred
+    /// look. The seed only drives artwork, never ownership or value, so this is accepted;
+    /// rarity-sensitive collections can use `mint_with_reveal` instead.
+    fn entropy_from(
+        block_number: u64,
+        msg_sender: Address,
+        chain_id: u64,
+        token_id: U256,
+        recipient: Address,
+    ) -> FixedBytes<32> {
         let hash_data =
             (block_number, msg_sender, chain_id, token_id, recipient).abi_encode_sequence();
         keccak(&hash_data)
     }
 
+    /// Seed for a commit-reveal mint. `mint_with_reveal` only accepts a `salt` set
+    /// after the issuer committed to `secret`, and the recipient chose it without
+    /// knowing `secret`, so neither side can steer the result alone.
+    fn revealed_entropy(
+        secret: FixedBytes<32>,
+        salt: FixedBytes<32>,
+        token_id: U256,
+        recipient: Address,
+    ) -> FixedBytes<32> {
+        keccak((secret, salt, token_id, recipient).abi_encode_sequence())
+    }
+
+    /// Internal function to compute the id after `token_id`, refusing to wrap around
+    fn successor_id(token_id: U256) -> Result<U256, SBTErrors> {
+        token_id
+            .checked_add(U256::from(1))
+            .ok_or(SBTErrors::IdSpaceExhausted(IdSpaceExhausted {}))
+    }
+
+    /// Internal function behind `mint_to_many` and `mint_unique`. With `unique`,
+    /// a repeated recipient fails the batch during the checks.
+    fn mint_batch(
+        &mut self,
+        recipients: Vec<Address>,
+        unique: bool,
+    ) -> Result<Vec<U256>, SBTErrors> {
+        // Only issuer can mint
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if recipients.is_empty() {
+            return Err(SBTErrors::EmptyArray(EmptyArray {}));
+        }
+
+        // Checks: validate every recipient and assign ids before touching storage
+        let mut seen = BTreeSet::new();
+        let mut token_ids = Vec::with_capacity(recipients.len());
+        let mut current_token_id = self.next_token_id.get();
+        for recipient in recipients.iter() {
+            if recipient.is_zero() {
+                return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
+            }
+            if unique && !seen.insert(*recipient) {
+                return Err(SBTErrors::DuplicateRecipient(DuplicateRecipient {
+                    recipient: *recipient,
+                }));
+            }
+            token_ids.push(current_token_id);
+            current_token_id = Self::successor_id(current_token_id)?;
+        }
+
+        // Effects: the whole batch is in storage before anything leaves the contract
+        let mut seeds = Vec::with_capacity(token_ids.len());
+        for (token_id, recipient) in token_ids.iter().zip(recipients.iter()) {
+            seeds.push(self.record_mint(*token_id, *recipient));
+        }
+        self.next_token_id.set(current_token_id);
+        let minted = self.minted_count.get();
+        self.minted_count.set(minted + U256::from(token_ids.len()));
+
+        for ((token_id, recipient), seed) in token_ids.iter().zip(recipients.iter()).zip(seeds) {
+            self.log_mint(*recipient, *token_id, seed);
+        }
+
+        Ok(token_ids)
+    }
+
+    /// Internal function to write all state for a freshly minted token.
+    /// Returns the seed for `log_mint`.
+    fn record_mint(&mut self, token_id: U256, to: Address) -> FixedBytes<32> {
+        let seed = self.generate_entropy(token_id, to);
+        self.record_mint_with_seed(token_id, to, seed);
+        seed
+    }
+
+    fn record_mint_with_seed(&mut self, token_id: U256, to: Address, seed: FixedBytes<32>) {
+        self.entropy.setter(token_id).set(seed);
+        self.owners.insert(token_id, to);
+        self.minted_at
+            .insert(token_id, U256::from(self.vm().block_timestamp()));
+        let current_balance = self.balances.get(to);
+        self.balances.insert(to, current_balance + U256::from(1));
+    }
+
+    /// Internal function to emit the standard Transfer plus the Minted event
+    /// renderers use to get the seed without reading storage
+    fn log_mint(&self, to: Address, token_id: U256, seed: FixedBytes<32>) {
+        log(
+            self.vm(),
+            Transfer {
+                from: Address::ZERO,
+                to,
+                tokenId: token_id,
+            },
+        );
+        log(
+            self.vm(),
+            Minted {
+                to,
+                tokenId: token_id,
+                seed,
+            },
+        );
+    }
+
+    /// Internal function to announce a rename and ask indexers to refresh every token
+    fn announce_rename(&mut self) {
+        log(
+            self.vm(),
+            CollectionRenamed {
+                name: self.name.get_string(),
+                symbol: self.symbol.get_string(),
+            },
+        );
+        self.refresh_all_metadata();
+    }
+
+    /// Internal function to ask indexers to refresh every minted token
+    fn refresh_all_metadata(&mut self) {
+        let next_token_id = self.next_token_id.get();
+        if next_token_id > U256::from(1) {
+            log(
+                self.vm(),
+                BatchMetadataUpdate {
+                    _fromTokenId: U256::from(1),
+                    _toTokenId: next_token_id - U256::from(1),
+                },
+            );
+        }
+    }
+
     /// Internal function to check if token exists
     fn token_exists(&self, token_id: U256) -> bool {
         !self.owners.get(token_id).is_zero()
     }
+
+    /// Build the artwork config from storage, filling unset values with the defaults
+    fn generator_config(&self) -> generator::GeneratorConfig {
+        let mut config = generator::GeneratorConfig::default();
+        let width = self.svg_width.get().to::<u32>();
+        let height = self.svg_height.get().to::<u32>();
+        let background = self.svg_background.get_string();
+        if width != 0 {
+            config.width = width;
+        }
+        if height != 0 {
+            config.height = height;
+        }
+        if !background.is_empty() {
+            config.background = background;
+        }
+        config
+    }
+
+    /// Read the factory's default base URI, if a factory is set and has one configured
+    fn factory_base_uri(&self) -> Option<String> {
+        let factory = self.sbt_factory.get();
+        if factory.is_zero() {
+            return None;
+        }
+        let calldata = ISBTFactory::defaultBaseUriCall {}.abi_encode();
+        let returned = self
+            .vm()
+            .static_call(&Call::new(), factory, &calldata)
+            .ok()?;
+        let base_uri = ISBTFactory::defaultBaseUriCall::abi_decode_returns(&returned, true)
+            .ok()?
+            ._0;
+        if base_uri.is_empty() {
+            return None;
+        }
+        Some(base_uri)
+    }
 }
 
 #[public]
 impl SBT {
     #[constructor]
+    #[allow(clippy::too_many_arguments)]
     fn constructor(
         &mut self,
         name: String,
         symbol: String,
         issuer: Address,
+        sbt_factory: Address,
+        svg_width: u32,
+        svg_height: u32,
+        svg_background: String,
     ) -> Result<(), SBTErrors> {
-        if issuer.is_zero() {
+        // A zero issuer is only allowed when the factory will set it later via `initialize`
+        if issuer.is_zero() && sbt_factory.is_zero() {
             return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
         }
+        if svg_width > MAX_SVG_DIMENSION
+            || svg_height > MAX_SVG_DIMENSION
+            || (!svg_background.is_empty()
+                && !generator::GeneratorConfig::is_valid_background(&svg_background))
+        {
+            return Err(SBTErrors::InvalidSvgConfig(InvalidSvgConfig {}));
+        }
         // Set SBT specific storage
         self.name.set_str(&name);
         self.symbol.set_str(&symbol);
         self.issuer.set(issuer);
+        self.sbt_factory.set(sbt_factory);
+        self.svg_width.set(U32::from(svg_width));
+        self.svg_height.set(U32::from(svg_height));
+        self.svg_background.set_str(&svg_background);
+        // Ids start at 1 so that token 0 never exists
         self.next_token_id.set(U256::from(1));
+
+        log(
+            self.vm(),
+            SBTInitialized {
+                name,
+                symbol,
+                issuer,
+            },
+        );
+        Ok(())
+    }
+
+    /// Lets the factory set the issuer after a deploy that could not pass one (e.g. CREATE2)
+    fn initialize(&mut self, issuer: Address) -> Result<(), SBTErrors> {
+        let factory = self.sbt_factory.get();
+        if factory.is_zero() || self.vm().msg_sender() != factory {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if !self.issuer.get().is_zero() {
+            return Err(SBTErrors::AlreadyInitialized(AlreadyInitialized {}));
+        }
+        if issuer.is_zero() {
+            return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
+        }
+
+        self.issuer.set(issuer);
+
+        log(
+            self.vm(),
+            SBTInitialized {
+                name: self.name.get_string(),
+                symbol: self.symbol.get_string(),
+                issuer,
+            },
+        );
+        Ok(())
+    }
+
+    /// Recovery path for a lost issuer key, driven by the factory that governs this collection.
+    /// The caller is checked against the stored factory only: the factory is mid-call here and
+    /// Stylus contracts are not reentrant, so calling back into it to confirm would revert.
+    fn factory_set_issuer(&mut self, new_issuer: Address) -> Result<(), SBTErrors> {
+        let factory = self.sbt_factory.get();
+        if factory.is_zero() || self.vm().msg_sender() != factory {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if new_issuer.is_zero() {
+            return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
+        }
+
+        let previous_issuer = self.issuer.get();
+        self.issuer.set(new_issuer);
+
+        log(
+            self.vm(),
+            IssuerUpdated {
+                previous_issuer,
+                new_issuer,
+            },
+        );
         Ok(())
     }
 
@@ -99,13 +456,94 @@ impl SBT {
         self.symbol.get_string()
     }
 
-    /// Returns the number of tokens in account's wallet
+    /// Rename the collection; generated metadata picks up the new name
+    fn set_name(&mut self, name: String) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if name.is_empty() {
+            return Err(SBTErrors::EmptyString(EmptyString {}));
+        }
+        self.name.set_str(&name);
+        self.announce_rename();
+        Ok(())
+    }
+
+    fn set_symbol(&mut self, symbol: String) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if symbol.is_empty() {
+            return Err(SBTErrors::EmptyString(EmptyString {}));
+        }
+        self.symbol.set_str(&symbol);
+        self.announce_rename();
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        self.description.get_string()
+    }
+
+    /// Set the metadata description; an empty string restores the default
+    fn set_description(&mut self, description: String) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        self.description.set_str(&description);
+        self.refresh_all_metadata();
+        Ok(())
+    }
+
+    fn placeholder_uri(&self) -> String {
+        self.placeholder_uri.get_string()
+    }
+
+    fn placeholder_uri_enabled(&self) -> bool {
+        self.placeholder_uri_enabled.get()
+    }
+
+    /// Serve `placeholder_uri` for unminted ids instead of reverting. Disabled by
+    /// default so spec-strict consumers still see `TokenNotExists`.
+    fn set_placeholder_uri(
+        &mut self,
+        placeholder_uri: String,
+        enabled: bool,
+    ) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if enabled && placeholder_uri.is_empty() {
+            return Err(SBTErrors::EmptyString(EmptyString {}));
+        }
+        self.placeholder_uri.set_str(&placeholder_uri);
+        self.placeholder_uri_enabled.set(enabled);
+        Ok(())
+    }
+
+    /// Returns the number of tokens in account's wallet.
+    /// Reverts for the zero address, as ERC-721 requires
     #[selector(name = "balanceOf")]
-    fn balance_of(&self, owner: Address) -> U256 {
+    fn balance_of(&self, owner: Address) -> Result<U256, SBTErrors> {
         if owner.is_zero() {
-            return U256::ZERO;
+            return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
+        }
+        Ok(self.balances.get(owner))
+    }
+
+    /// Returns the balance of each account in order (ERC-1155 style convenience read)
+    fn balance_of_batch(&self, owners: Vec<Address>) -> Result<Vec<U256>, SBTErrors> {
+        if owners.len() > MAX_BATCH_SIZE {
+            return Err(SBTErrors::BatchTooLarge(BatchTooLarge {
+                length: U256::from(owners.len()),
+                max: U256::from(MAX_BATCH_SIZE),
+            }));
         }
-        self.balances.get(owner)
+        // Lenient for dashboards: the zero address reads as zero here
+        Ok(owners
+            .iter()
+            .map(|owner| self.balances.get(*owner))
+            .collect())
     }
 
     /// Returns the owner of the token_id token
@@ -113,22 +551,82 @@ impl SBT {
     fn owner_of(&self, token_id: U256) -> Result<Address, SBTErrors> {
         let owner = self.owners.get(token_id);
         if owner.is_zero() {
-            return Err(SBTErrors::TokenNotExists(TokenNotExists {}));
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
         }
         Ok(owner)
     }
 
-    /// Generate token URI with circular design
+    /// Returns the block timestamp at which the token was minted
+    fn minted_at(&self, token_id: U256) -> Result<U256, SBTErrors> {
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        Ok(self.minted_at.get(token_id))
+    }
+
+    /// Returns whether the credential currently counts; unminted and frozen ids are not valid
+    fn is_valid(&self, token_id: U256) -> bool {
+        self.token_exists(token_id) && !self.frozen.get(token_id)
+    }
+
+    fn is_frozen(&self, token_id: U256) -> bool {
+        self.frozen.get(token_id)
+    }
+
+    /// Returns owner, token URI, mint timestamp and validity in a single call
+    fn get_token_data(&self, token_id: U256) -> Result<(Address, String, U256, bool), SBTErrors> {
+        let owner = self.owner_of(token_id)?;
+        let token_uri = self.token_uri(token_id)?;
+        let minted_at = self.minted_at(token_id)?;
+        Ok((owner, token_uri, minted_at, self.is_valid(token_id)))
+    }
+
+    /// Generate token URI with circular design, unless the factory provides a default base URI
     #[selector(name = "tokenURI")]
     fn token_uri(&self, token_id: U256) -> Result<String, SBTErrors> {
         if !self.token_exists(token_id) {
-            return Err(SBTErrors::TokenNotExists(TokenNotExists {}));
+            if self.placeholder_uri_enabled.get() {
+                return Ok(self.placeholder_uri.get_string());
+            }
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        // Off-chain metadata learns about freezes through the MetadataUpdate event
+        if let Some(base_uri) = self.factory_base_uri() {
+            return Ok(format!("{}{}", base_uri, token_id));
         }
         let seed = self.entropy.get(token_id);
-        let generator = generator::SBTGenerator::new(seed);
+        let mut generator = generator::SBTGenerator::with_config(seed, self.generator_config())
+            .with_owner(self.owners.get(token_id))
+            .with_name(&self.name.get_string())
+            .with_chain_id(self.vm().chain_id());
+        let description = self.description.get_string();
+        if !description.is_empty() {
+            generator = generator.with_description(&description);
+        }
+        if self.frozen.get(token_id) {
+            return Ok(generator.frozen_metadata());
+        }
         Ok(generator.metadata())
     }
 
+    /// Returns the seed behind a token's artwork, for rendering it off-chain
+    fn get_seed(&self, token_id: U256) -> Result<FixedBytes<32>, SBTErrors> {
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        Ok(self.entropy.get(token_id))
+    }
+
+    /// Seed-derived rarity of a token, also published as a metadata attribute
+    fn rarity_score(&self, token_id: U256) -> Result<U256, SBTErrors> {
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        let seed = self.entropy.get(token_id);
+        let generator = generator::SBTGenerator::with_config(seed, self.generator_config());
+        Ok(U256::from(generator.rarity_score()))
+    }
+
     fn mint_to_one(&mut self, to: Address) -> Result<U256, SBTErrors> {
         if self.vm().msg_sender() != self.issuer.get() {
             return Err(SBTErrors::Unauthorized(Unauthorized {}));
@@ -137,109 +635,375 @@ impl SBT {
             return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
         }
         let token_id = self.next_token_id.get();
-        let seed = self.generate_entropy(token_id, to);
-        self.entropy.setter(token_id).set(seed);
-        self.owners.insert(token_id, to);
-        let current_balance = self.balances.get(to);
-        self.balances.insert(to, current_balance + U256::from(1));
-        self.next_token_id.set(token_id + U256::from(1));
+        let next_token_id = Self::successor_id(token_id)?;
+        let seed = self.record_mint(token_id, to);
+        self.next_token_id.set(next_token_id);
+        let minted = self.minted_count.get();
+        self.minted_count.set(minted + U256::from(1));
 
-        log(
-            self.vm(),
-            Transfer {
-                from: Address::ZERO,
-                to,
-                tokenId: token_id,
-            },
-        );
+        self.log_mint(to, token_id, seed);
 
         Ok(token_id)
     }
 
     fn mint_to_many(&mut self, recipients: Vec<Address>) -> Result<Vec<U256>, SBTErrors> {
-        // Only issuer can mint
+        self.mint_batch(recipients, false)
+    }
+
+    /// Like `mint_to_many`, but one token per distinct address: a repeated
+    /// recipient reverts the whole batch.
+    fn mint_unique(&mut self, recipients: Vec<Address>) -> Result<Vec<U256>, SBTErrors> {
+        self.mint_batch(recipients, true)
+    }
+
+    /// Commit to the secret for the next `mint_with_reveal`. A zero commitment
+    /// withdraws a pending one. Salts set before this call no longer count.
+    fn commit_entropy(&mut self, commitment: FixedBytes<32>) -> Result<(), SBTErrors> {
         if self.vm().msg_sender() != self.issuer.get() {
             return Err(SBTErrors::Unauthorized(Unauthorized {}));
         }
-        if recipients.is_empty() {
-            return Err(SBTErrors::EmptyArray(EmptyArray {}));
-        }
+        self.entropy_commitment.set(commitment);
+        let commits = self.entropy_commit_count.get();
+        self.entropy_commit_count.set(commits + U256::from(1));
 
-        let mut token_ids = Vec::new();
-        let mut current_token_id = self.next_token_id.get();
-
-        for recipient in recipients.iter() {
-            if recipient.is_zero() {
-                return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
-            }
+        log(self.vm(), EntropyCommitted { commitment });
+        Ok(())
+    }
 
-            // Generate entropy for this token
-            let seed = self.generate_entropy(current_token_id, *recipient);
-            self.entropy.setter(current_token_id).set(seed);
-            self.owners.insert(current_token_id, *recipient);
+    /// Set the salt mixed into the caller's next revealed seed. Only counts
+    /// once the issuer has committed, so set it after `EntropyCommitted`.
+    fn set_mint_salt(&mut self, salt: FixedBytes<32>) {
+        let sender = self.vm().msg_sender();
+        self.mint_salts.insert(sender, salt);
+        let commits = self.entropy_commit_count.get();
+        self.mint_salt_commits.insert(sender, commits);
+    }
 
-            let current_balance = self.balances.get(*recipient);
-            self.balances
-                .insert(*recipient, current_balance + U256::from(1));
+    fn get_entropy_commitment(&self) -> FixedBytes<32> {
+        self.entropy_commitment.get()
+    }
 
-            log(
-                self.vm(),
-                Transfer {
-                    from: Address::ZERO,
-                    to: *recipient,
-                    tokenId: current_token_id,
-                },
-            );
+    fn get_mint_salt(&self, account: Address) -> FixedBytes<32> {
+        self.mint_salts.get(account)
+    }
 
-            token_ids.push(current_token_id);
-            current_token_id += U256::from(1);
+    /// Mint with a seed drawn from the committed secret and the recipient's salt
+    /// instead of block data the issuer could grind. Consumes both.
+    fn mint_with_reveal(&mut self, to: Address, secret: FixedBytes<32>) -> Result<U256, SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if to.is_zero() {
+            return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
+        }
+        let commitment = self.entropy_commitment.get();
+        if commitment.is_zero() || keccak(secret) != commitment {
+            return Err(SBTErrors::InvalidReveal(InvalidReveal {}));
+        }
+        // A salt the issuer could see before committing would let them grind `secret`
+        let salt = self.mint_salts.get(to);
+        if salt.is_zero() || self.mint_salt_commits.get(to) != self.entropy_commit_count.get() {
+            return Err(SBTErrors::InvalidMintSalt(InvalidMintSalt {
+                recipient: to,
+            }));
         }
 
-        // Update next token ID
-        self.next_token_id.set(current_token_id);
+        let token_id = self.next_token_id.get();
+        let next_token_id = Self::successor_id(token_id)?;
+        let seed = Self::revealed_entropy(secret, salt, token_id, to);
+        self.record_mint_with_seed(token_id, to, seed);
+        self.next_token_id.set(next_token_id);
+        let minted = self.minted_count.get();
+        self.minted_count.set(minted + U256::from(1));
+        self.entropy_commitment.set(FixedBytes::ZERO);
+        self.mint_salts.delete(to);
+        self.mint_salt_commits.delete(to);
 
-        Ok(token_ids)
-    }
+        self.log_mint(to, token_id, seed);
 
-    fn get_issuer(&self) -> Address {
-        self.issuer.get()
+        Ok(token_id)
     }
 
-    fn get_next_token_id(&self) -> U256 {
-        self.next_token_id.get()
-    }
+    /// Lenient `mint_to_many` for large airdrop lists: zero addresses, and any
+    /// recipients left once the id space runs out, are skipped instead of
+    /// reverting. Returns each recipient with whether it was minted.
+    fn try_mint_to_many(
+        &mut self,
+        recipients: Vec<Address>,
+    ) -> Result<Vec<(Address, bool)>, SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if recipients.is_empty() {
+            return Err(SBTErrors::EmptyArray(EmptyArray {}));
+        }
 
-    fn total_supply(&self) -> U256 {
-        let next_id = self.next_token_id.get();
-        if next_id == U256::from(1) {
-            U256::ZERO
-        } else {
-            next_id - U256::from(1)
+        // Checks: decide every entry and assign ids before touching storage
+        let mut report = Vec::with_capacity(recipients.len());
+        let mut minted = Vec::new();
+        let mut current_token_id = self.next_token_id.get();
+        for recipient in recipients {
+            let next_token_id = match Self::successor_id(current_token_id) {
+                Ok(next) if !recipient.is_zero() => next,
+                _ => {
+                    report.push((recipient, false));
+                    continue;
+                }
+            };
+            minted.push((current_token_id, recipient));
+            report.push((recipient, true));
+            current_token_id = next_token_id;
         }
-    }
 
-    /// Check if interface is supported (minimal ERC165 implementation)
+        // Effects
+        let mut seeds = Vec::with_capacity(minted.len());
+        for (token_id, recipient) in minted.iter() {
+            seeds.push(self.record_mint(*token_id, *recipient));
+        }
+        self.next_token_id.set(current_token_id);
+        let minted_count = self.minted_count.get();
+        self.minted_count
+            .set(minted_count + U256::from(minted.len()));
+
+        for ((token_id, recipient), seed) in minted.iter().zip(seeds) {
+            self.log_mint(*recipient, *token_id, seed);
+        }
+
+        Ok(report)
+    }
+
+    /// Mint like `mint_to_one`, then require contract recipients to accept the
+    /// token through `onERC721Received`. The token is fully recorded before the
+    /// callback, and Stylus refuses reentry by default, so the receiver cannot
+    /// mint again from inside it.
+    fn safe_mint_to_one(&mut self, to: Address, data: Bytes) -> Result<U256, SBTErrors> {
+        let token_id = self.mint_to_one(to)?;
+        if self.vm().code_size(to) == 0 {
+            return Ok(token_id);
+        }
+
+        let calldata = IERC721Receiver::onERC721ReceivedCall {
+            operator: self.vm().msg_sender(),
+            from: Address::ZERO,
+            tokenId: token_id,
+            data: data.0.into(),
+        }
+        .abi_encode();
+
+        let returned = self.vm().call(&Call::new(), to, &calldata);
+
+        let accepted = returned
+            .ok()
+            .and_then(|ret| {
+                IERC721Receiver::onERC721ReceivedCall::abi_decode_returns(&ret, true).ok()
+            })
+            .is_some_and(|ret| ret._0 == IERC721Receiver::onERC721ReceivedCall::SELECTOR);
+        if !accepted {
+            return Err(SBTErrors::InvalidReceiver(InvalidReceiver { receiver: to }));
+        }
+
+        Ok(token_id)
+    }
+
+    /// Alias of `mint_to_one` under the name used in the original design docs
+    fn issue_sbt(&mut self, to: Address) -> Result<U256, SBTErrors> {
+        self.mint_to_one(to)
+    }
+
+    fn get_issuer(&self) -> Address {
+        self.issuer.get()
+    }
+
+    fn get_sbt_factory(&self) -> Address {
+        self.sbt_factory.get()
+    }
+
+    fn get_next_token_id(&self) -> U256 {
+        self.next_token_id.get()
+    }
+
+    #[selector(name = "totalSupply")]
+    fn total_supply(&self) -> U256 {
+        self.minted_count.get() - self.burned_count.get()
+    }
+
+    /// Check if interface is supported (minimal ERC165 implementation)
     #[selector(name = "supportsInterface")]
     fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
         // ERC721 interface ID: 0x80ac58cd
         // ERC165 interface ID: 0x01ffc9a7
+        // ERC5192 interface ID: 0xb45a3c0e
+        // ERC4906 interface ID: 0x49064906
+        // IEthosSBT interface ID: 0x3f32e904
         interface_id == FixedBytes([0x80, 0xac, 0x58, 0xcd]) || // ERC721
-            interface_id == FixedBytes([0x01, 0xff, 0xc9, 0xa7]) // ERC165
+            interface_id == FixedBytes([0x01, 0xff, 0xc9, 0xa7]) || // ERC165
+            interface_id == FixedBytes([0xb4, 0x5a, 0x3c, 0x0e]) || // ERC5192
+            interface_id == FixedBytes([0x49, 0x06, 0x49, 0x06]) || // ERC4906
+            interface_id == IETHOS_SBT_INTERFACE_ID // IEthosSBT
+    }
+
+    /// Returns whether the token is soulbound (ERC-5192)
+    fn locked(&self, token_id: U256) -> Result<bool, SBTErrors> {
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        Ok(!self.unlocked.get(token_id))
+    }
+
+    /// Escape hatch: lets the issuer make a single token transferable
+    fn unlock(&mut self, token_id: U256) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        self.unlocked.insert(token_id, true);
+
+        log(self.vm(), Unlocked { tokenId: token_id });
+        Ok(())
+    }
+
+    /// Restores the soulbound guarantee for a previously unlocked token
+    fn lock(&mut self, token_id: U256) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        self.unlocked.insert(token_id, false);
+
+        log(self.vm(), Locked { tokenId: token_id });
+        Ok(())
+    }
+
+    /// Revoke a credential permanently. Its id is never reissued.
+    fn burn(&mut self, token_id: U256) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+
+        let owner = self.owners.get(token_id);
+        self.owners.delete(token_id);
+        self.entropy.delete(token_id);
+        self.minted_at.delete(token_id);
+        self.frozen.delete(token_id);
+        self.unlocked.delete(token_id);
+        let current_balance = self.balances.get(owner);
+        self.balances.insert(owner, current_balance - U256::from(1));
+        let burned = self.burned_count.get();
+        self.burned_count.set(burned + U256::from(1));
+
+        log(
+            self.vm(),
+            Transfer {
+                from: owner,
+                to: Address::ZERO,
+                tokenId: token_id,
+            },
+        );
+
+        // Reputation tied to the credential goes with it; if the hook fails the burn reverts
+        let hook = self.burn_hook.get();
+        if !hook.is_zero() {
+            let calldata = IReputationHook::onCredentialBurnedCall { holder: owner }.abi_encode();
+            if self.vm().call(&Call::new(), hook, &calldata).is_err() {
+                return Err(SBTErrors::BurnHookFailed(BurnHookFailed { hook }));
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the reputation contract notified of burns; zero turns the hook off
+    fn set_burn_hook(&mut self, hook: Address) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        self.burn_hook.set(hook);
+
+        log(self.vm(), BurnHookUpdated { hook });
+        Ok(())
+    }
+
+    fn get_burn_hook(&self) -> Address {
+        self.burn_hook.get()
+    }
+
+    /// Temporarily suspend a credential without destroying it
+    fn freeze(&mut self, token_id: U256) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        self.frozen.insert(token_id, true);
+
+        log(self.vm(), Frozen { tokenId: token_id });
+        log(self.vm(), MetadataUpdate { _tokenId: token_id });
+        Ok(())
+    }
+
+    /// Lift a freeze so the credential counts again
+    fn thaw(&mut self, token_id: U256) -> Result<(), SBTErrors> {
+        if self.vm().msg_sender() != self.issuer.get() {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if !self.token_exists(token_id) {
+            return Err(SBTErrors::TokenNotExists(TokenNotExists { token_id }));
+        }
+        self.frozen.insert(token_id, false);
+
+        log(self.vm(), Thawed { tokenId: token_id });
+        log(self.vm(), MetadataUpdate { _tokenId: token_id });
+        Ok(())
     }
 
-    ///#######################################################
-    /// DISABLED TRANSFER FUNCTIONS (Soulbound implementation)
-    ///########################################################
+    // #######################################################
+    // DISABLED TRANSFER FUNCTIONS (Soulbound implementation)
+    // #######################################################
 
-    /// Disabled: SBTs cannot be transferred
+    /// Disabled: SBTs cannot be transferred, unless the issuer unlocked the token
     #[selector(name = "transferFrom")]
     fn transfer_from(
         &mut self,
-        _from: Address,
-        _to: Address,
-        _token_id: U256,
+        from: Address,
+        to: Address,
+        token_id: U256,
     ) -> Result<(), SBTErrors> {
-        Err(SBTErrors::NotTransferable(NotTransferable {}))
+        // A frozen credential stays put even if unlocked, so a dispute cannot be dodged
+        if !self.unlocked.get(token_id) || self.frozen.get(token_id) {
+            return Err(SBTErrors::NotTransferable(NotTransferable {}));
+        }
+        // Approvals stay disabled, so only the holder can move an unlocked token
+        let owner = self.owners.get(token_id);
+        if owner != from || self.vm().msg_sender() != owner {
+            return Err(SBTErrors::Unauthorized(Unauthorized {}));
+        }
+        if to.is_zero() {
+            return Err(SBTErrors::ZeroAddress(ZeroAddress {}));
+        }
+
+        self.owners.insert(token_id, to);
+        let from_balance = self.balances.get(from);
+        self.balances.insert(from, from_balance - U256::from(1));
+        let to_balance = self.balances.get(to);
+        self.balances.insert(to, to_balance + U256::from(1));
+
+        log(
+            self.vm(),
+            Transfer {
+                from,
+                to,
+                tokenId: token_id,
+            },
+        );
+
+        Ok(())
     }
 
     /// Disabled: SBTs cannot be approved for transfer
@@ -251,58 +1015,1357 @@ impl SBT {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::address;
+    use alloy_primitives::{address, keccak256, B256};
+    use alloy_sol_types::{SolError, SolEvent};
     use stylus_sdk::testing::*;
 
     #[no_mangle]
     pub unsafe extern "C" fn emit_log(_pointer: *const u8, _len: usize, _: usize) {}
 
-    fn setup_sbt() -> (TestVM, SBT) {
-        let vm = TestVM::default();
-        let mut sbt = SBT::from(&vm);
+    /// First slot past the SBT layout (end of the gap); adding fields must not move it
+    const SBT_STORAGE_END: u64 = 58;
+
+    fn setup_sbt() -> (TestVM, SBT) {
+        setup_sbt_with_factory(Address::ZERO)
+    }
+
+    fn setup_sbt_with_factory(factory: Address) -> (TestVM, SBT) {
+        let vm = TestVM::default();
+        let mut sbt = SBT::from(&vm);
+
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        vm.set_sender(issuer);
+
+        let result = sbt.constructor(
+            "Test SBT".to_string(),
+            "TSBT".to_string(),
+            issuer,
+            factory,
+            0,
+            0,
+            String::new(),
+        );
+        assert!(result.is_ok());
+
+        (vm, sbt)
+    }
+
+    #[test]
+    fn test_constructor_emits_initialized() {
+        let (vm, _sbt) = setup_sbt();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 1);
+        let (topics, data) = &logs[0];
+        let event = SBTInitialized::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(SBTInitialized { name, symbol, issuer: logged })
+                if name == "Test SBT" && symbol == "TSBT" && logged == issuer
+        ));
+    }
+
+    // FACTORY INITIALIZATION TESTS
+
+    fn setup_uninitialized_sbt(factory: Address) -> (TestVM, SBT) {
+        let vm = TestVM::default();
+        let mut sbt = SBT::from(&vm);
+
+        vm.set_sender(factory);
+        let result = sbt.constructor(
+            "Test SBT".to_string(),
+            "TSBT".to_string(),
+            Address::ZERO,
+            factory,
+            0,
+            0,
+            String::new(),
+        );
+        assert!(result.is_ok());
+
+        (vm, sbt)
+    }
+
+    #[test]
+    fn test_initialize_after_constructor_issuer() {
+        let factory = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let (vm, mut sbt) = setup_sbt_with_factory(factory);
+        let other = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+
+        vm.set_sender(factory);
+        assert!(matches!(
+            sbt.initialize(other),
+            Err(SBTErrors::AlreadyInitialized(_))
+        ));
+    }
+
+    #[test]
+    fn test_factory_set_issuer_rejections() {
+        let factory = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let other = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let (vm, mut sbt) = setup_sbt_with_factory(factory);
+
+        // Neither the issuer nor anyone else besides the factory can reassign
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.factory_set_issuer(other),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+
+        vm.set_sender(factory);
+        assert!(matches!(
+            sbt.factory_set_issuer(Address::ZERO),
+            Err(SBTErrors::ZeroAddress(_))
+        ));
+        assert_eq!(sbt.get_issuer(), issuer);
+
+        // Without a factory there is no recovery path at all
+        let (vm, mut sbt) = setup_sbt();
+        vm.set_sender(Address::ZERO);
+        assert!(matches!(
+            sbt.factory_set_issuer(other),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_constructor_zero_issuer_without_factory() {
+        let vm = TestVM::default();
+        let mut sbt = SBT::from(&vm);
+
+        let result = sbt.constructor(
+            "Test SBT".to_string(),
+            "TSBT".to_string(),
+            Address::ZERO,
+            Address::ZERO,
+            0,
+            0,
+            String::new(),
+        );
+        assert!(matches!(result, Err(SBTErrors::ZeroAddress(_))));
+    }
+
+    #[test]
+    fn test_uninitialized_sbt_cannot_mint() {
+        let factory = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let (vm, mut sbt) = setup_uninitialized_sbt(factory);
+
+        vm.set_sender(factory);
+        assert!(matches!(
+            sbt.mint_to_one(recipient),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_erc721_view_selectors_are_camel_case() {
+        let (_vm, mut sbt) = setup_sbt();
+
+        let route = |sbt: &mut SBT, signature: &str| {
+            let selector = keccak256(signature.as_bytes());
+            let selector = u32::from_be_bytes([selector[0], selector[1], selector[2], selector[3]]);
+            <SBT as stylus_sdk::abi::Router<SBT>>::route(sbt, selector, &[]).is_some()
+        };
+
+        assert!(route(&mut sbt, "totalSupply()"));
+        assert!(route(&mut sbt, "name()"));
+        assert!(route(&mut sbt, "symbol()"));
+        assert!(!route(&mut sbt, "total_supply()"));
+    }
+
+    #[test]
+    fn test_mint_with_reveal_flow() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let secret = B256::repeat_byte(0x5e);
+        let salt = B256::repeat_byte(0xa1);
+
+        vm.set_sender(issuer);
+        assert!(sbt.commit_entropy(keccak256(secret)).is_ok());
+        assert_eq!(sbt.get_entropy_commitment(), keccak256(secret));
+
+        vm.set_sender(alice);
+        sbt.set_mint_salt(salt);
+        assert_eq!(sbt.get_mint_salt(alice), salt);
+
+        vm.set_sender(issuer);
+        let result = sbt.mint_with_reveal(alice, secret);
+        assert!(matches!(result, Ok(id) if id == U256::from(1)));
+        assert!(matches!(sbt.owner_of(U256::from(1)), Ok(owner) if owner == alice));
+        assert_eq!(sbt.total_supply(), U256::from(1));
+
+        // The seed comes from the secret and salt, not from block data
+        assert_eq!(
+            sbt.entropy.get(U256::from(1)),
+            SBT::revealed_entropy(secret, salt, U256::from(1), alice)
+        );
+        assert_ne!(
+            sbt.entropy.get(U256::from(1)),
+            sbt.generate_entropy(U256::from(1), alice)
+        );
+
+        // Commitment and salt are single-use
+        assert_eq!(sbt.get_entropy_commitment(), B256::ZERO);
+        assert_eq!(sbt.get_mint_salt(alice), B256::ZERO);
+        assert!(matches!(
+            sbt.mint_with_reveal(alice, secret),
+            Err(SBTErrors::InvalidReveal(_))
+        ));
+    }
+
+    #[test]
+    fn test_mint_with_reveal_requires_salt_after_commit() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let secret = B256::repeat_byte(0x5e);
+        let salt = B256::repeat_byte(0xa1);
+
+        // Alice's salt is public before the issuer commits, so it cannot be used
+        vm.set_sender(alice);
+        sbt.set_mint_salt(salt);
+        vm.set_sender(issuer);
+        assert!(sbt.commit_entropy(keccak256(secret)).is_ok());
+        assert!(matches!(
+            sbt.mint_with_reveal(alice, secret),
+            Err(SBTErrors::InvalidMintSalt(InvalidMintSalt { recipient })) if recipient == alice
+        ));
+
+        // Bob never set one; an unset (zero) salt is refused too
+        assert!(matches!(
+            sbt.mint_with_reveal(bob, secret),
+            Err(SBTErrors::InvalidMintSalt(_))
+        ));
+        vm.set_sender(bob);
+        sbt.set_mint_salt(B256::ZERO);
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.mint_with_reveal(bob, secret),
+            Err(SBTErrors::InvalidMintSalt(_))
+        ));
+        assert_eq!(sbt.total_supply(), U256::ZERO);
+
+        // Committing again after seeing a salt invalidates it
+        vm.set_sender(alice);
+        sbt.set_mint_salt(salt);
+        vm.set_sender(issuer);
+        assert!(sbt.commit_entropy(keccak256(secret)).is_ok());
+        assert!(matches!(
+            sbt.mint_with_reveal(alice, secret),
+            Err(SBTErrors::InvalidMintSalt(_))
+        ));
+
+        // A fresh salt after the latest commitment is accepted
+        vm.set_sender(alice);
+        sbt.set_mint_salt(salt);
+        vm.set_sender(issuer);
+        assert!(sbt.mint_with_reveal(alice, secret).is_ok());
+    }
+
+    #[test]
+    fn test_mint_with_reveal_rejects_mismatch() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let secret = B256::repeat_byte(0x5e);
+
+        vm.set_sender(issuer);
+        // Nothing committed yet
+        assert!(matches!(
+            sbt.mint_with_reveal(alice, secret),
+            Err(SBTErrors::InvalidReveal(_))
+        ));
+
+        assert!(sbt.commit_entropy(keccak256(secret)).is_ok());
+        assert!(matches!(
+            sbt.mint_with_reveal(alice, B256::repeat_byte(0x5f)),
+            Err(SBTErrors::InvalidReveal(_))
+        ));
+        assert_eq!(sbt.total_supply(), U256::ZERO);
+        assert_eq!(sbt.get_entropy_commitment(), keccak256(secret));
+
+        // Only the issuer commits or reveals
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.commit_entropy(keccak256(secret)),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+        assert!(matches!(
+            sbt.mint_with_reveal(alice, secret),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_entropy_is_deterministic() {
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let seed = SBT::entropy_from(10, issuer, 42161, U256::from(1), alice);
+
+        assert_eq!(
+            seed,
+            SBT::entropy_from(10, issuer, 42161, U256::from(1), alice)
+        );
+    }
+
+    #[test]
+    fn test_entropy_changes_with_each_input() {
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let seed = SBT::entropy_from(10, issuer, 42161, U256::from(1), alice);
+
+        let variants = [
+            SBT::entropy_from(11, issuer, 42161, U256::from(1), alice),
+            SBT::entropy_from(10, bob, 42161, U256::from(1), alice),
+            SBT::entropy_from(10, issuer, 421614, U256::from(1), alice),
+            SBT::entropy_from(10, issuer, 42161, U256::from(2), alice),
+            SBT::entropy_from(10, issuer, 42161, U256::from(1), bob),
+        ];
+        for variant in variants {
+            assert_ne!(variant, seed);
+        }
+    }
+
+    #[test]
+    fn test_minted_entropy_uses_vm_context() {
+        let (vm, mut sbt) = setup_sbt();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(issuer);
+        vm.set_block_number(77);
+        vm.set_chain_id(42161);
+        assert!(sbt.mint_to_one(alice).is_ok());
+
+        assert_eq!(
+            sbt.entropy.get(U256::from(1)),
+            SBT::entropy_from(77, issuer, 42161, U256::from(1), alice)
+        );
+    }
+
+    /// Decoded mint Transfers and Minted events, each in emission order
+    fn mint_events(vm: &TestVM) -> (Vec<Transfer>, Vec<Minted>) {
+        let mut transfers = Vec::new();
+        let mut minted = Vec::new();
+        for (topics, data) in vm.get_emitted_logs() {
+            if let Ok(event) = Transfer::decode_raw_log(topics.iter().copied(), &data, true) {
+                if event.from == Address::ZERO {
+                    transfers.push(event);
+                }
+            } else if let Ok(event) = Minted::decode_raw_log(topics.iter().copied(), &data, true) {
+                minted.push(event);
+            }
+        }
+        (transfers, minted)
+    }
+
+    #[test]
+    fn test_minted_event_accompanies_transfer() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let carol = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        assert!(sbt.mint_to_many(vec![bob, carol]).is_ok());
+
+        let (transfers, minted) = mint_events(&vm);
+        assert_eq!(transfers.len(), 3);
+        assert_eq!(minted.len(), 3);
+        for (transfer, mint) in transfers.iter().zip(minted.iter()) {
+            assert_eq!(transfer.to, mint.to);
+            assert_eq!(transfer.tokenId, mint.tokenId);
+            // The logged seed is the stored one renderers would otherwise read
+            assert_eq!(mint.seed, sbt.entropy.get(mint.tokenId));
+        }
+
+        // Each Minted directly follows its Transfer
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        assert!(Minted::decode_raw_log(topics.iter().copied(), data, true).is_ok());
+        let (topics, data) = &logs[logs.len() - 2];
+        assert!(Transfer::decode_raw_log(topics.iter().copied(), data, true).is_ok());
+    }
+
+    #[test]
+    fn test_mint_transfers_once_per_id_in_order() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let carol = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        assert!(sbt.mint_to_many(vec![bob, carol, alice]).is_ok());
+        assert!(sbt.mint_to_one(carol).is_ok());
+        assert!(sbt.mint_to_many(vec![bob]).is_ok());
+
+        // Every Transfer in the log, not just mints, so a stray one would show up
+        let transfers: Vec<Transfer> = vm
+            .get_emitted_logs()
+            .iter()
+            .filter_map(|(topics, data)| {
+                Transfer::decode_raw_log(topics.iter().copied(), data, true).ok()
+            })
+            .collect();
+
+        let expected_recipients = [alice, bob, carol, alice, carol, bob];
+        assert_eq!(transfers.len(), expected_recipients.len());
+        for (i, (transfer, recipient)) in transfers.iter().zip(expected_recipients).enumerate() {
+            assert_eq!(transfer.from, Address::ZERO);
+            assert_eq!(transfer.to, recipient);
+            // Ids start at 1 and rise by one per Transfer, with no repeats or gaps
+            assert_eq!(transfer.tokenId, U256::from(i + 1));
+        }
+        assert_eq!(
+            sbt.get_next_token_id(),
+            U256::from(expected_recipients.len() + 1)
+        );
+    }
+
+    #[test]
+    fn test_initialization() {
+        let (_vm, sbt) = setup_sbt();
+        assert_eq!(
+            sbt.get_issuer(),
+            address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266")
+        );
+        assert_eq!(sbt.get_next_token_id(), U256::from(1));
+        assert_eq!(sbt.total_supply(), U256::ZERO);
+        assert_eq!(sbt.name(), "Test SBT");
+        assert_eq!(sbt.symbol(), "TSBT");
+    }
+
+    #[test]
+    fn test_mint_and_balance() {
+        let (vm, mut sbt) = setup_sbt();
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+
+        let result = sbt.mint_to_one(recipient);
+        assert!(result.is_ok());
+
+        if let Ok(token_id) = result {
+            assert_eq!(token_id, U256::from(1));
+            assert!(matches!(sbt.balance_of(recipient), Ok(balance) if balance == U256::from(1)));
+            assert!(sbt.owner_of(token_id).is_ok());
+            assert_eq!(sbt.total_supply(), U256::from(1));
+            if let Ok(addr) = sbt.owner_of(token_id) {
+                assert_eq!(addr, recipient);
+            }
+        }
+    }
+
+    #[test]
+    fn test_token_ids_start_at_one_and_increase() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let mut ids = Vec::new();
+        if let Ok(id) = sbt.mint_to_one(alice) {
+            ids.push(id);
+        }
+        if let Ok(batch) = sbt.mint_to_many(vec![alice, bob]) {
+            ids.extend(batch);
+        }
+        if let Ok(id) = sbt.mint_to_one(bob) {
+            ids.push(id);
+        }
+
+        // Id 0 is never minted, so it can stand for "nonexistent"
+        assert_eq!(ids, (1..=4).map(U256::from).collect::<Vec<_>>());
+        assert!(matches!(
+            sbt.owner_of(U256::ZERO),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_mint_id_space_exhausted() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        sbt.next_token_id.set(U256::MAX - U256::from(1));
+
+        // The last representable id before MAX can still be minted
+        assert!(matches!(sbt.mint_to_one(alice), Ok(id) if id == U256::MAX - U256::from(1)));
+
+        assert!(matches!(
+            sbt.mint_to_one(alice),
+            Err(SBTErrors::IdSpaceExhausted(_))
+        ));
+        assert!(matches!(
+            sbt.mint_to_many(vec![alice]),
+            Err(SBTErrors::IdSpaceExhausted(_))
+        ));
+        assert_eq!(sbt.next_token_id.get(), U256::MAX);
+    }
+
+    #[test]
+    fn test_try_mint_to_many_skips_invalid_recipients() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let result = sbt.try_mint_to_many(vec![alice, Address::ZERO, bob, Address::ZERO]);
+        assert!(matches!(
+            result,
+            Ok(report) if report == vec![
+                (alice, true),
+                (Address::ZERO, false),
+                (bob, true),
+                (Address::ZERO, false),
+            ]
+        ));
+
+        // Only the valid entries were minted, with consecutive ids
+        assert_eq!(sbt.total_supply(), U256::from(2));
+        assert!(matches!(sbt.owner_of(U256::from(1)), Ok(owner) if owner == alice));
+        assert!(matches!(sbt.owner_of(U256::from(2)), Ok(owner) if owner == bob));
+        assert_eq!(sbt.get_next_token_id(), U256::from(3));
+        // SBTInitialized + Transfer and Minted for each of the 2 tokens
+        assert_eq!(vm.get_emitted_logs().len(), 5);
+
+        // The strict variant still rejects the same list outright
+        assert!(matches!(
+            sbt.mint_to_many(vec![alice, Address::ZERO]),
+            Err(SBTErrors::ZeroAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_try_mint_to_many_stops_at_id_space_end() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        sbt.next_token_id.set(U256::MAX - U256::from(1));
+
+        let result = sbt.try_mint_to_many(vec![alice, bob]);
+        assert!(matches!(
+            result,
+            Ok(report) if report == vec![(alice, true), (bob, false)]
+        ));
+        assert!(matches!(sbt.balance_of(alice), Ok(balance) if balance == U256::from(1)));
+        assert!(matches!(sbt.balance_of(bob), Ok(balance) if balance == U256::ZERO));
+        assert_eq!(sbt.get_next_token_id(), U256::MAX);
+    }
+
+    #[test]
+    fn test_try_mint_to_many_access_and_empty() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.try_mint_to_many(vec![alice]),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.try_mint_to_many(Vec::new()),
+            Err(SBTErrors::EmptyArray(_))
+        ));
+    }
+
+    #[test]
+    fn test_rename_updates_name_symbol_and_metadata() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_many(vec![alice, bob]).is_ok());
+        let token_id = U256::from(1);
+        let before = sbt.token_uri(token_id);
+
+        assert!(sbt.set_name("Guild Badge".to_string()).is_ok());
+        assert!(sbt.set_symbol("GLD".to_string()).is_ok());
+        assert_eq!(sbt.name(), "Guild Badge");
+        assert_eq!(sbt.symbol(), "GLD");
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 2];
+        let renamed = CollectionRenamed::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            renamed,
+            Ok(CollectionRenamed { name, symbol }) if name == "Guild Badge" && symbol == "GLD"
+        ));
+        let (topics, data) = &logs[logs.len() - 1];
+        let refresh = BatchMetadataUpdate::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            refresh,
+            Ok(BatchMetadataUpdate { _fromTokenId, _toTokenId })
+                if _fromTokenId == U256::from(1) && _toTokenId == U256::from(2)
+        ));
+
+        // Generated metadata now carries the new name
+        let expected = generator::SBTGenerator::with_config(
+            sbt.entropy.get(token_id),
+            generator::GeneratorConfig::default(),
+        )
+        .with_owner(alice)
+        .with_chain_id(42161)
+        .with_name("Guild Badge")
+        .metadata();
+        let after = sbt.token_uri(token_id);
+        assert!(matches!(&after, Ok(uri) if *uri == expected));
+        assert!(matches!((before, after), (Ok(old), Ok(new)) if old != new));
+    }
+
+    #[test]
+    fn test_token_uri_placeholder_for_unminted_id() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let unminted = U256::from(7);
+
+        // Default: unminted ids revert
+        assert!(!sbt.placeholder_uri_enabled());
+        assert!(matches!(
+            sbt.token_uri(unminted),
+            Err(SBTErrors::TokenNotExists(TokenNotExists { token_id })) if token_id == unminted
+        ));
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.set_placeholder_uri("ipfs://soon".to_string(), true),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.set_placeholder_uri(String::new(), true),
+            Err(SBTErrors::EmptyString(_))
+        ));
+        assert!(sbt
+            .set_placeholder_uri("ipfs://soon".to_string(), true)
+            .is_ok());
+        assert!(sbt.placeholder_uri_enabled());
+        assert_eq!(sbt.placeholder_uri(), "ipfs://soon");
+        assert!(matches!(sbt.token_uri(unminted), Ok(uri) if uri == "ipfs://soon"));
+
+        // Minted tokens still get their real metadata
+        assert!(sbt.mint_to_one(alice).is_ok());
+        assert!(matches!(
+            sbt.token_uri(U256::from(1)),
+            Ok(uri) if uri.starts_with("data:application/json;base64,")
+        ));
+
+        // Switching it off restores the revert
+        assert!(sbt.set_placeholder_uri(String::new(), false).is_ok());
+        assert!(matches!(
+            sbt.token_uri(unminted),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_set_description_updates_metadata() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        let token_id = U256::from(1);
+        let base = generator::SBTGenerator::with_config(
+            sbt.entropy.get(token_id),
+            generator::GeneratorConfig::default(),
+        )
+        .with_owner(alice)
+        .with_chain_id(42161)
+        .with_name("Test SBT");
+
+        // Unset: the generator default is used
+        assert_eq!(sbt.description(), "");
+        let default = base.metadata();
+        assert!(matches!(sbt.token_uri(token_id), Ok(uri) if uri == default));
+
+        assert!(sbt
+            .set_description("Members of the \"Guild\"".to_string())
+            .is_ok());
+        assert_eq!(sbt.description(), "Members of the \"Guild\"");
+        let custom = base.with_description("Members of the \"Guild\"").metadata();
+        assert!(matches!(sbt.token_uri(token_id), Ok(uri) if uri == custom));
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let refresh = BatchMetadataUpdate::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            refresh,
+            Ok(BatchMetadataUpdate { _fromTokenId, _toTokenId })
+                if _fromTokenId == U256::from(1) && _toTokenId == U256::from(1)
+        ));
+
+        // Clearing it restores the default
+        assert!(sbt.set_description(String::new()).is_ok());
+        assert!(matches!(sbt.token_uri(token_id), Ok(uri) if uri == default));
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.set_description("Hijacked".to_string()),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_rename_rejections() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.set_name("Hijacked".to_string()),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+        assert!(matches!(
+            sbt.set_symbol("HJK".to_string()),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.set_name(String::new()),
+            Err(SBTErrors::EmptyString(_))
+        ));
+        assert!(matches!(
+            sbt.set_symbol(String::new()),
+            Err(SBTErrors::EmptyString(_))
+        ));
+        assert_eq!(sbt.name(), "Test SBT");
+        assert_eq!(sbt.symbol(), "TSBT");
+
+        // Nothing minted yet, so there is no token range to refresh
+        assert!(sbt.set_name("Renamed".to_string()).is_ok());
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        assert!(CollectionRenamed::decode_raw_log(topics.iter().copied(), data, true).is_ok());
+    }
+
+    #[test]
+    fn test_token_uri_reflects_chain_id() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        vm.set_chain_id(8453);
+        assert!(sbt.mint_to_one(alice).is_ok());
+
+        let rendered = |chain_id| {
+            generator::SBTGenerator::with_config(
+                sbt.entropy.get(U256::from(1)),
+                generator::GeneratorConfig::default(),
+            )
+            .with_owner(alice)
+            .with_name("Test SBT")
+            .with_chain_id(chain_id)
+            .metadata()
+        };
+        let on_base = rendered(8453);
+        let on_arbitrum = rendered(42161);
+        assert!(matches!(sbt.token_uri(U256::from(1)), Ok(uri) if uri == on_base));
+        assert!(matches!(sbt.token_uri(U256::from(1)), Ok(uri) if uri != on_arbitrum));
+    }
+
+    #[test]
+    fn test_get_seed_reproduces_artwork() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+
+        let seed = sbt.entropy.get(U256::from(1));
+        assert!(matches!(sbt.get_seed(U256::from(1)), Ok(s) if s == seed));
+
+        // The seed alone is enough to rebuild the on-chain metadata and SVG
+        let rendered =
+            generator::SBTGenerator::with_config(seed, generator::GeneratorConfig::default())
+                .with_owner(alice)
+                .with_chain_id(42161)
+                .with_name(&sbt.name.get_string())
+                .metadata();
+        assert!(matches!(sbt.token_uri(U256::from(1)), Ok(uri) if uri == rendered));
+
+        assert!(matches!(
+            sbt.get_seed(U256::from(2)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_rarity_score_view() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_many(vec![alice, bob]).is_ok());
+        // Pin known seeds so the scores are fixed
+        sbt.entropy
+            .setter(U256::from(1))
+            .set(FixedBytes::from([0u8; 32]));
+        sbt.entropy
+            .setter(U256::from(2))
+            .set(FixedBytes::from([128u8; 32]));
+
+        let first = sbt.rarity_score(U256::from(1));
+        let second = sbt.rarity_score(U256::from(2));
+        assert!(matches!(first, Ok(score) if score == U256::from(200)));
+        assert!(matches!(second, Ok(score) if score == U256::ZERO));
+        assert!(matches!(sbt.rarity_score(U256::from(1)), Ok(score) if score == U256::from(200)));
+
+        assert!(matches!(
+            sbt.rarity_score(U256::from(3)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_burn_reduces_supply_but_not_next_id() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        assert!(sbt.mint_to_many(vec![alice, bob]).is_ok());
+        assert_eq!(sbt.total_supply(), U256::from(3));
+
+        assert!(sbt.burn(U256::from(2)).is_ok());
+        assert_eq!(sbt.total_supply(), U256::from(2));
+        assert!(matches!(sbt.balance_of(alice), Ok(balance) if balance == U256::from(1)));
+        assert!(matches!(
+            sbt.owner_of(U256::from(2)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+        assert!(matches!(
+            sbt.minted_at(U256::from(2)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+        assert!(!sbt.is_valid(U256::from(2)));
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = Transfer::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(Transfer { from, to, tokenId }) if from == alice && to == Address::ZERO && tokenId == U256::from(2)
+        ));
+
+        // Ids keep advancing; the burned id is never reissued
+        assert_eq!(sbt.get_next_token_id(), U256::from(4));
+        assert!(matches!(sbt.mint_to_one(bob), Ok(id) if id == U256::from(4)));
+        assert_eq!(sbt.total_supply(), U256::from(3));
+        assert_eq!(sbt.get_next_token_id(), U256::from(5));
+    }
+
+    #[test]
+    fn test_burn_rejections() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+
+        // Holders cannot burn their own credential
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.burn(U256::from(1)),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+
+        vm.set_sender(issuer);
+        assert!(sbt.burn(U256::from(1)).is_ok());
+        assert!(matches!(
+            sbt.burn(U256::from(1)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+        assert!(matches!(
+            sbt.burn(U256::from(7)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+        assert_eq!(sbt.total_supply(), U256::ZERO);
+    }
+
+    #[test]
+    fn test_burn_notifies_hook() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let carol = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let hook = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_many(vec![alice, bob, carol]).is_ok());
+        assert!(sbt.set_burn_hook(hook).is_ok());
+        assert_eq!(sbt.get_burn_hook(), hook);
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = BurnHookUpdated::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(event, Ok(BurnHookUpdated { hook: h }) if h == hook));
+
+        vm.mock_call(
+            hook,
+            IReputationHook::onCredentialBurnedCall { holder: alice }.abi_encode(),
+            Ok(Vec::new()),
+        );
+        assert!(sbt.burn(U256::from(1)).is_ok());
+        assert_eq!(sbt.total_supply(), U256::from(2));
+
+        // A reverting hook fails the burn, and the host rolls its effects back
+        vm.mock_call(
+            hook,
+            IReputationHook::onCredentialBurnedCall { holder: bob }.abi_encode(),
+            Err(Vec::new()),
+        );
+        assert!(matches!(
+            sbt.burn(U256::from(2)),
+            Err(SBTErrors::BurnHookFailed(BurnHookFailed { hook: h })) if h == hook
+        ));
+
+        // Turning the hook off lets the issuer burn without it
+        vm.mock_call(
+            hook,
+            IReputationHook::onCredentialBurnedCall { holder: carol }.abi_encode(),
+            Err(Vec::new()),
+        );
+        assert!(sbt.set_burn_hook(Address::ZERO).is_ok());
+        assert!(sbt.burn(U256::from(3)).is_ok());
+    }
+
+    #[test]
+    fn test_set_burn_hook_unauthorized() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.set_burn_hook(alice),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+        assert_eq!(sbt.get_burn_hook(), Address::ZERO);
+    }
+
+    #[test]
+    fn test_minted_at_and_is_valid() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        vm.set_block_timestamp(1_000);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        vm.set_block_timestamp(2_000);
+        assert!(sbt.mint_to_many(vec![bob]).is_ok());
+
+        assert!(matches!(sbt.minted_at(U256::from(1)), Ok(t) if t == U256::from(1_000)));
+        assert!(matches!(sbt.minted_at(U256::from(2)), Ok(t) if t == U256::from(2_000)));
+        assert!(matches!(
+            sbt.minted_at(U256::from(3)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+
+        assert!(sbt.is_valid(U256::from(1)));
+        assert!(!sbt.is_valid(U256::from(3)));
+    }
+
+    // FREEZE TESTS
+
+    #[test]
+    fn test_freeze_and_thaw() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let token_id = U256::from(1);
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        let plain_uri = sbt.token_uri(token_id);
+        assert!(sbt.is_valid(token_id));
+
+        assert!(sbt.freeze(token_id).is_ok());
+        assert!(sbt.is_frozen(token_id));
+        assert!(!sbt.is_valid(token_id));
+
+        // Frozen tokens keep their owner but render with a Frozen status
+        assert!(matches!(sbt.owner_of(token_id), Ok(owner) if owner == alice));
+        let expected = generator::SBTGenerator::with_config(
+            sbt.entropy.get(token_id),
+            generator::GeneratorConfig::default(),
+        )
+        .with_owner(alice)
+        .with_chain_id(42161)
+        .with_name("Test SBT")
+        .frozen_metadata();
+        assert!(matches!(sbt.token_uri(token_id), Ok(uri) if uri == expected));
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = MetadataUpdate::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(event, Ok(MetadataUpdate { _tokenId }) if _tokenId == token_id));
+
+        assert!(sbt.thaw(token_id).is_ok());
+        assert!(!sbt.is_frozen(token_id));
+        assert!(sbt.is_valid(token_id));
+        assert!(matches!(
+            (sbt.token_uri(token_id), plain_uri),
+            (Ok(uri), Ok(plain)) if uri == plain
+        ));
+    }
+
+    #[test]
+    fn test_freeze_access_control() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let token_id = U256::from(1);
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+
+        // The holder cannot freeze or thaw their own credential
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.freeze(token_id),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+
+        vm.set_sender(issuer);
+        assert!(sbt.freeze(token_id).is_ok());
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.thaw(token_id),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+        assert!(sbt.is_frozen(token_id));
+
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.freeze(U256::from(2)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_frozen_unlocked_token_cannot_transfer() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let token_id = U256::from(1);
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        assert!(sbt.unlock(token_id).is_ok());
+        assert!(sbt.freeze(token_id).is_ok());
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.transfer_from(alice, bob, token_id),
+            Err(SBTErrors::NotTransferable(_))
+        ));
+        assert!(matches!(sbt.owner_of(token_id), Ok(owner) if owner == alice));
+
+        // Once thawed, the unlock applies again
+        vm.set_sender(issuer);
+        assert!(sbt.thaw(token_id).is_ok());
+        vm.set_sender(alice);
+        assert!(sbt.transfer_from(alice, bob, token_id).is_ok());
+        assert!(matches!(sbt.owner_of(token_id), Ok(owner) if owner == bob));
+    }
+
+    #[test]
+    fn test_get_token_data_matches_getters() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        vm.set_block_timestamp(1_234);
+        assert!(sbt.mint_to_one(alice).is_ok());
+        let token_id = U256::from(1);
+
+        let data = sbt.get_token_data(token_id);
+        assert!(data.is_ok());
+        if let (
+            Ok((owner, uri, minted_at, valid)),
+            Ok(expected_owner),
+            Ok(expected_uri),
+            Ok(expected_minted_at),
+        ) = (
+            data,
+            sbt.owner_of(token_id),
+            sbt.token_uri(token_id),
+            sbt.minted_at(token_id),
+        ) {
+            assert_eq!(owner, expected_owner);
+            assert_eq!(uri, expected_uri);
+            assert_eq!(minted_at, expected_minted_at);
+            assert_eq!(valid, sbt.is_valid(token_id));
+        }
+
+        assert!(matches!(
+            sbt.get_token_data(U256::from(2)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_issue_sbt_matches_mint_to_one() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(matches!(sbt.mint_to_one(alice), Ok(id) if id == U256::from(1)));
+        assert!(matches!(sbt.issue_sbt(bob), Ok(id) if id == U256::from(2)));
+
+        assert!(matches!(sbt.owner_of(U256::from(2)), Ok(owner) if owner == bob));
+        assert!(matches!(sbt.balance_of(bob), Ok(balance) if balance == U256::from(1)));
+        assert_eq!(sbt.total_supply(), U256::from(2));
+        assert!(matches!(
+            sbt.issue_sbt(Address::ZERO),
+            Err(SBTErrors::ZeroAddress(_))
+        ));
+
+        // Both names are issuer-gated
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.issue_sbt(alice),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+        assert!(matches!(
+            sbt.mint_to_one(alice),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+    }
+
+    fn mock_receiver(vm: &TestVM, receiver: Address, token_id: U256, response: FixedBytes<4>) {
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        vm.set_code(receiver, vec![0x00]);
+        vm.mock_call(
+            receiver,
+            IERC721Receiver::onERC721ReceivedCall {
+                operator: issuer,
+                from: Address::ZERO,
+                tokenId: token_id,
+                data: Vec::new().into(),
+            }
+            .abi_encode(),
+            Ok(IERC721Receiver::onERC721ReceivedCall::abi_encode_returns(
+                &(response,),
+            )),
+        );
+    }
+
+    #[test]
+    fn test_mint_to_many_writes_nothing_on_invalid_recipient() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let logs_before = vm.get_emitted_logs().len();
+        assert!(matches!(
+            sbt.mint_to_many(vec![alice, Address::ZERO]),
+            Err(SBTErrors::ZeroAddress(_))
+        ));
+
+        // All recipients are checked before the first write
+        assert!(matches!(sbt.balance_of(alice), Ok(balance) if balance == U256::ZERO));
+        assert_eq!(sbt.total_supply(), U256::ZERO);
+        assert!(!sbt.token_exists(U256::from(1)));
+        assert_eq!(vm.get_emitted_logs().len(), logs_before);
+    }
+
+    #[test]
+    fn test_mint_unique_distinct_recipients() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let minted = sbt.mint_unique(vec![alice, bob]);
+
+        assert!(matches!(minted, Ok(ids) if ids == vec![U256::from(1), U256::from(2)]));
+        assert!(matches!(sbt.owner_of(U256::from(2)), Ok(owner) if owner == bob));
+        assert_eq!(sbt.total_supply(), U256::from(2));
+    }
+
+    #[test]
+    fn test_mint_unique_rejects_duplicates() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.mint_unique(vec![alice, bob, alice]),
+            Err(SBTErrors::DuplicateRecipient(DuplicateRecipient { recipient })) if recipient == alice
+        ));
+        assert_eq!(sbt.total_supply(), U256::ZERO);
+
+        // The permissive batch still accepts the same list
+        assert!(sbt.mint_to_many(vec![alice, bob, alice]).is_ok());
+        assert!(matches!(sbt.balance_of(alice), Ok(balance) if balance == U256::from(2)));
+    }
+
+    #[test]
+    fn test_mint_unique_checks_issuer_before_duplicates() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            sbt.mint_unique(vec![alice, bob, alice]),
+            Err(SBTErrors::Unauthorized(_))
+        ));
+    }
 
+    #[test]
+    fn test_safe_mint_to_account_without_code() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
         let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
         vm.set_sender(issuer);
+        let result = sbt.safe_mint_to_one(alice, Bytes(Vec::new()));
+        assert!(matches!(result, Ok(id) if id == U256::from(1)));
+        assert!(matches!(sbt.owner_of(U256::from(1)), Ok(owner) if owner == alice));
+    }
 
-        let result = sbt.constructor("Test SBT".to_string(), "TSBT".to_string(), issuer);
-        assert!(result.is_ok());
+    #[test]
+    fn test_safe_mint_to_accepting_receiver() {
+        let (vm, mut sbt) = setup_sbt();
+        let receiver = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
 
-        (vm, sbt)
+        mock_receiver(
+            &vm,
+            receiver,
+            U256::from(1),
+            IERC721Receiver::onERC721ReceivedCall::SELECTOR.into(),
+        );
+
+        vm.set_sender(issuer);
+        let result = sbt.safe_mint_to_one(receiver, Bytes(Vec::new()));
+        assert!(matches!(result, Ok(id) if id == U256::from(1)));
+        assert!(matches!(sbt.balance_of(receiver), Ok(balance) if balance == U256::from(1)));
+        assert!(sbt.mint_to_one(alice).is_ok());
     }
 
     #[test]
-    fn test_initialization() {
-        let (_vm, sbt) = setup_sbt();
-        assert_eq!(
-            sbt.get_issuer(),
-            address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266")
+    fn test_reentering_receiver_sees_one_complete_mint() {
+        let (vm, mut sbt) = setup_sbt();
+        let receiver = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        // The receiver tries to mint again from inside onERC721Received; the host
+        // refuses the reentrant call, so the callback itself reverts
+        vm.set_code(receiver, vec![0x00]);
+        vm.mock_call(
+            receiver,
+            IERC721Receiver::onERC721ReceivedCall {
+                operator: issuer,
+                from: Address::ZERO,
+                tokenId: U256::from(1),
+                data: Vec::new().into(),
+            }
+            .abi_encode(),
+            Err(Vec::new()),
         );
-        assert_eq!(sbt.get_next_token_id(), U256::from(1));
-        assert_eq!(sbt.total_supply(), U256::ZERO);
-        assert_eq!(sbt.name(), "Test SBT");
-        assert_eq!(sbt.symbol(), "TSBT");
+
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.safe_mint_to_one(receiver, Bytes(Vec::new())),
+            Err(SBTErrors::InvalidReceiver(_))
+        ));
+
+        // TestVM keeps what was written before the callback, which is exactly one
+        // complete mint; on-chain the revert then undoes it as a whole
+        assert_eq!(sbt.next_token_id.get(), U256::from(2));
+        assert_eq!(sbt.minted_count.get(), U256::from(1));
+        assert!(matches!(sbt.owner_of(U256::from(1)), Ok(owner) if owner == receiver));
+        assert!(matches!(sbt.balance_of(receiver), Ok(balance) if balance == U256::from(1)));
+        assert!(!sbt.token_exists(U256::from(2)));
+
+        // The next batch picks up right after it, with no gap or partial entries
+        let result = sbt.mint_to_many(vec![alice, bob]);
+        assert!(matches!(result, Ok(ids) if ids == vec![U256::from(2), U256::from(3)]));
+        assert_eq!(sbt.next_token_id.get(), U256::from(4));
+        assert_eq!(sbt.minted_count.get(), U256::from(3));
+        assert!(matches!(sbt.balance_of(alice), Ok(balance) if balance == U256::from(1)));
+        assert!(matches!(sbt.balance_of(bob), Ok(balance) if balance == U256::from(1)));
+        assert!(matches!(sbt.balance_of(receiver), Ok(balance) if balance == U256::from(1)));
     }
 
     #[test]
-    fn test_mint_and_balance() {
+    fn test_safe_mint_to_rejecting_receiver() {
         let (vm, mut sbt) = setup_sbt();
-        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let receiver = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let silent = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
         let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
 
-        vm.set_sender(issuer);
+        mock_receiver(
+            &vm,
+            receiver,
+            U256::from(1),
+            FixedBytes([0xde, 0xad, 0xbe, 0xef]),
+        );
 
-        let result = sbt.mint_to_one(recipient);
-        assert!(result.is_ok());
+        vm.set_sender(issuer);
+        let result = sbt.safe_mint_to_one(receiver, Bytes(Vec::new()));
+        assert!(matches!(
+            result,
+            Err(SBTErrors::InvalidReceiver(InvalidReceiver { receiver: r })) if r == receiver
+        ));
 
-        if let Ok(token_id) = result {
-            assert_eq!(token_id, U256::from(1));
-            assert_eq!(sbt.balance_of(recipient), U256::from(1));
-            assert!(sbt.owner_of(token_id).is_ok());
-            assert_eq!(sbt.total_supply(), U256::from(1));
-            if let Ok(addr) = sbt.owner_of(token_id) {
-                assert_eq!(addr, recipient);
-            }
-        }
+        // A contract with no receiver hook at all is rejected too
+        vm.set_code(silent, vec![0x00]);
+        let result = sbt.safe_mint_to_one(silent, Bytes(Vec::new()));
+        assert!(matches!(result, Err(SBTErrors::InvalidReceiver(_))));
     }
 
     #[test]
@@ -374,8 +2437,420 @@ mod tests {
         let erc165_id = FixedBytes([0x01, 0xff, 0xc9, 0xa7]);
         assert!(sbt.supports_interface(erc165_id));
 
+        // Test ERC5192 interface
+        let erc5192_id = FixedBytes([0xb4, 0x5a, 0x3c, 0x0e]);
+        assert!(sbt.supports_interface(erc5192_id));
+
+        // Test ERC4906 interface, backing the MetadataUpdate events
+        let erc4906_id = FixedBytes([0x49, 0x06, 0x49, 0x06]);
+        assert!(sbt.supports_interface(erc4906_id));
+
         // Test unsupported interface
         let random_id = FixedBytes([0x12, 0x34, 0x56, 0x78]);
         assert!(!sbt.supports_interface(random_id));
     }
+
+    sol! {
+        interface IEthosSBT {
+            function getIssuer() external view returns (address);
+            function mintToOne(address to) external returns (uint256);
+            function mintToMany(address[] recipients) external returns (uint256[]);
+        }
+    }
+
+    #[test]
+    fn test_supports_soulbound_interface() {
+        let (_vm, sbt) = setup_sbt();
+
+        // The id is the XOR of the soulbound collection selectors
+        let selectors = [
+            IEthosSBT::getIssuerCall::SELECTOR,
+            IEthosSBT::mintToOneCall::SELECTOR,
+            IEthosSBT::mintToManyCall::SELECTOR,
+        ];
+        let expected: [u8; 4] =
+            core::array::from_fn(|i| selectors.iter().fold(0, |acc, s| acc ^ s[i]));
+        assert_eq!(IETHOS_SBT_INTERFACE_ID, FixedBytes(expected));
+
+        assert!(sbt.supports_interface(IETHOS_SBT_INTERFACE_ID));
+        assert_ne!(
+            IETHOS_SBT_INTERFACE_ID,
+            FixedBytes([0x80, 0xac, 0x58, 0xcd])
+        );
+        assert_ne!(
+            IETHOS_SBT_INTERFACE_ID,
+            FixedBytes([0xb4, 0x5a, 0x3c, 0x0e])
+        );
+    }
+
+    #[test]
+    fn test_token_uri_falls_back_to_factory_base_uri() {
+        let factory = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let (vm, mut sbt) = setup_sbt_with_factory(factory);
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.mock_static_call(
+            factory,
+            ISBTFactory::defaultBaseUriCall {}.abi_encode(),
+            Ok(ISBTFactory::defaultBaseUriCall::abi_encode_returns(&(
+                "https://meta.ethos.xyz/".to_string(),
+            ))),
+        );
+
+        assert_eq!(sbt.get_sbt_factory(), factory);
+
+        let result = sbt.mint_to_one(recipient);
+        assert!(result.is_ok());
+
+        if let Ok(token_id) = result {
+            let uri_result = sbt.token_uri(token_id);
+            assert!(uri_result.is_ok());
+
+            if let Ok(uri) = uri_result {
+                assert_eq!(uri, "https://meta.ethos.xyz/1");
+            }
+        }
+    }
+
+    #[test]
+    fn test_token_uri_ignores_empty_factory_base_uri() {
+        let factory = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let (vm, mut sbt) = setup_sbt_with_factory(factory);
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.mock_static_call(
+            factory,
+            ISBTFactory::defaultBaseUriCall {}.abi_encode(),
+            Ok(ISBTFactory::defaultBaseUriCall::abi_encode_returns(&(
+                String::new(),
+            ))),
+        );
+
+        let result = sbt.mint_to_one(recipient);
+        assert!(result.is_ok());
+
+        if let Ok(token_id) = result {
+            let uri_result = sbt.token_uri(token_id);
+            assert!(uri_result.is_ok());
+
+            if let Ok(uri) = uri_result {
+                assert!(uri.starts_with("data:application/json;base64,"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_storage_layout_with_gap() {
+        let (vm, mut sbt) = setup_sbt();
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(recipient).is_ok());
+
+        // Existing fields still read back and the gap stays untouched
+        assert_eq!(sbt.name(), "Test SBT");
+        assert!(matches!(sbt.balance_of(recipient), Ok(balance) if balance == U256::from(1)));
+        for i in 0..sbt.__gap.len() {
+            assert_eq!(sbt.__gap.get(i), Some(U256::ZERO));
+        }
+
+        // The last gap slot sits right before the fixed end of the layout
+        let sentinel = U256::from(0xe7405);
+        let last = sbt.__gap.len() - 1;
+        if let Some(mut slot) = sbt.__gap.setter(last) {
+            slot.set(sentinel);
+        }
+        assert_eq!(
+            vm.get_storage(U256::from(SBT_STORAGE_END - 1)),
+            B256::from(sentinel)
+        );
+    }
+
+    // SVG CONFIG TESTS
+
+    #[test]
+    fn test_default_svg_config() {
+        let (_vm, mut sbt) = setup_sbt();
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        let config = sbt.generator_config();
+        assert_eq!(config.width, 1000);
+        assert_eq!(config.height, 1000);
+        assert_eq!(config.background, "#0C0404");
+
+        // Unset config renders exactly what the default generator renders
+        assert!(sbt.mint_to_one(recipient).is_ok());
+        let token_id = U256::from(1);
+        let expected = generator::SBTGenerator::with_config(
+            sbt.entropy.get(token_id),
+            generator::GeneratorConfig::default(),
+        )
+        .with_owner(recipient)
+        .with_chain_id(42161)
+        .with_name("Test SBT")
+        .metadata();
+        assert!(matches!(sbt.token_uri(token_id), Ok(uri) if uri == expected));
+    }
+
+    #[test]
+    fn test_custom_svg_config() {
+        let vm = TestVM::default();
+        let mut sbt = SBT::from(&vm);
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(issuer);
+        let result = sbt.constructor(
+            "Test SBT".to_string(),
+            "TSBT".to_string(),
+            issuer,
+            Address::ZERO,
+            400,
+            300,
+            "#ffffff".to_string(),
+        );
+        assert!(result.is_ok());
+
+        let config = sbt.generator_config();
+        assert_eq!(config.width, 400);
+        assert_eq!(config.height, 300);
+        assert_eq!(config.background, "#ffffff");
+
+        assert!(sbt.mint_to_one(recipient).is_ok());
+        let token_id = U256::from(1);
+        let expected = generator::SBTGenerator::with_config(
+            sbt.entropy.get(token_id),
+            generator::GeneratorConfig {
+                width: 400,
+                height: 300,
+                background: "#ffffff".to_string(),
+            },
+        )
+        .with_owner(recipient)
+        .with_chain_id(42161)
+        .with_name("Test SBT")
+        .metadata();
+        assert!(matches!(sbt.token_uri(token_id), Ok(uri) if uri == expected));
+    }
+
+    #[test]
+    fn test_invalid_svg_config() {
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let invalid = [
+            (MAX_SVG_DIMENSION + 1, 0, String::new()),
+            (0, MAX_SVG_DIMENSION + 1, String::new()),
+            (0, 0, "red\"/><script>".to_string()),
+        ];
+
+        for (width, height, background) in invalid {
+            let vm = TestVM::default();
+            let mut sbt = SBT::from(&vm);
+            vm.set_sender(issuer);
+
+            let result = sbt.constructor(
+                "Test SBT".to_string(),
+                "TSBT".to_string(),
+                issuer,
+                Address::ZERO,
+                width,
+                height,
+                background,
+            );
+            assert!(matches!(result, Err(SBTErrors::InvalidSvgConfig(_))));
+        }
+    }
+
+    #[test]
+    fn test_balance_of_zero_address_reverts() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_one(alice).is_ok());
+
+        assert!(matches!(
+            sbt.balance_of(Address::ZERO),
+            Err(SBTErrors::ZeroAddress(_))
+        ));
+        assert!(matches!(sbt.balance_of(alice), Ok(balance) if balance == U256::from(1)));
+    }
+
+    #[test]
+    fn test_balance_of_batch() {
+        let (vm, mut sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let carol = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(sbt.mint_to_many(vec![alice, alice, carol]).is_ok());
+
+        let result = sbt.balance_of_batch(vec![alice, bob, Address::ZERO, carol]);
+        assert!(result.is_ok());
+
+        if let Ok(balances) = result {
+            assert_eq!(
+                balances,
+                vec![U256::from(2), U256::ZERO, U256::ZERO, U256::from(1)]
+            );
+        }
+    }
+
+    #[test]
+    fn test_balance_of_batch_too_large() {
+        let (_vm, sbt) = setup_sbt();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        let at_cap = sbt.balance_of_batch(vec![alice; MAX_BATCH_SIZE]);
+        assert!(matches!(at_cap, Ok(balances) if balances.len() == MAX_BATCH_SIZE));
+
+        let over_cap = sbt.balance_of_batch(vec![alice; MAX_BATCH_SIZE + 1]);
+        assert!(matches!(
+            over_cap,
+            Err(SBTErrors::BatchTooLarge(BatchTooLarge { length, max }))
+                if length == U256::from(MAX_BATCH_SIZE + 1) && max == U256::from(MAX_BATCH_SIZE)
+        ));
+    }
+
+    // UNLOCK TESTS
+
+    #[test]
+    fn test_unlocked_token_can_transfer() {
+        let (vm, mut sbt) = setup_sbt();
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let other = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let first = sbt.mint_to_one(recipient);
+        let second = sbt.mint_to_one(recipient);
+        assert!(first.is_ok() && second.is_ok());
+
+        if let (Ok(unlocked_id), Ok(locked_id)) = (first, second) {
+            assert!(sbt.unlock(unlocked_id).is_ok());
+            assert!(matches!(sbt.locked(unlocked_id), Ok(false)));
+            assert!(matches!(sbt.locked(locked_id), Ok(true)));
+
+            // The holder moves the unlocked token
+            vm.set_sender(recipient);
+            assert!(sbt.transfer_from(recipient, other, unlocked_id).is_ok());
+            assert!(matches!(sbt.owner_of(unlocked_id), Ok(addr) if addr == other));
+            assert!(matches!(sbt.balance_of(recipient), Ok(balance) if balance == U256::from(1)));
+            assert!(matches!(sbt.balance_of(other), Ok(balance) if balance == U256::from(1)));
+
+            // The still-locked token stays soulbound
+            let result = sbt.transfer_from(recipient, other, locked_id);
+            assert!(matches!(result, Err(SBTErrors::NotTransferable(_))));
+        }
+    }
+
+    #[test]
+    fn test_unlocked_token_transfer_requires_holder() {
+        let (vm, mut sbt) = setup_sbt();
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let other = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let result = sbt.mint_to_one(recipient);
+        assert!(result.is_ok());
+
+        if let Ok(token_id) = result {
+            assert!(sbt.unlock(token_id).is_ok());
+
+            // Issuer is not the holder and approvals are disabled
+            let transfer_result = sbt.transfer_from(recipient, other, token_id);
+            assert!(matches!(transfer_result, Err(SBTErrors::Unauthorized(_))));
+            let approve_result = sbt.approve(other, token_id);
+            assert!(matches!(approve_result, Err(SBTErrors::NotTransferable(_))));
+        }
+    }
+
+    #[test]
+    fn test_relock_restores_soulbound() {
+        let (vm, mut sbt) = setup_sbt();
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let other = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let result = sbt.mint_to_one(recipient);
+        assert!(result.is_ok());
+
+        if let Ok(token_id) = result {
+            assert!(sbt.unlock(token_id).is_ok());
+            assert!(sbt.lock(token_id).is_ok());
+            assert!(matches!(sbt.locked(token_id), Ok(true)));
+
+            vm.set_sender(recipient);
+            let transfer_result = sbt.transfer_from(recipient, other, token_id);
+            assert!(matches!(
+                transfer_result,
+                Err(SBTErrors::NotTransferable(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_unlock_unauthorized() {
+        let (vm, mut sbt) = setup_sbt();
+        let recipient = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let result = sbt.mint_to_one(recipient);
+        assert!(result.is_ok());
+
+        if let Ok(token_id) = result {
+            vm.set_sender(recipient);
+            assert!(matches!(
+                sbt.unlock(token_id),
+                Err(SBTErrors::Unauthorized(_))
+            ));
+            assert!(matches!(
+                sbt.lock(token_id),
+                Err(SBTErrors::Unauthorized(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_locked_nonexistent_token() {
+        let (vm, mut sbt) = setup_sbt();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        assert!(matches!(
+            sbt.locked(U256::from(1)),
+            Err(SBTErrors::TokenNotExists(TokenNotExists { token_id })) if token_id == U256::from(1)
+        ));
+
+        vm.set_sender(issuer);
+        assert!(matches!(
+            sbt.unlock(U256::from(1)),
+            Err(SBTErrors::TokenNotExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_token_not_exists_reports_token_id() {
+        let (_vm, sbt) = setup_sbt();
+        let missing = U256::from(42);
+
+        assert!(matches!(
+            sbt.owner_of(missing),
+            Err(SBTErrors::TokenNotExists(TokenNotExists { token_id })) if token_id == missing
+        ));
+
+        let result = sbt.token_uri(missing);
+        assert!(result.is_err());
+
+        if let Err(err) = result {
+            let revert_data: Vec<u8> = err.into();
+            let decoded = TokenNotExists::abi_decode(&revert_data, true);
+            assert!(matches!(decoded, Ok(TokenNotExists { token_id }) if token_id == missing));
+        }
+    }
 }
@@ -11,7 +11,14 @@ extern crate alloc;
 
 use alloc::{string::String, vec::Vec};
 use alloy_primitives::Address;
-use stylus_sdk::{alloy_primitives::U256, alloy_sol_types::sol, prelude::*};
+use alloy_sol_types::SolCall;
+use stylus_sdk::{
+    alloy_primitives::U256, alloy_sol_types::sol, crypto::keccak, prelude::*,
+    stylus_core::calls::context::Call,
+};
+
+/// Seconds the owner has to confirm a renounce after initiating it
+const RENOUNCE_WINDOW: u64 = 3600;
 
 sol_storage! {
     #[entrypoint]
@@ -24,6 +31,27 @@ sol_storage! {
         mapping(address => bool) is_valid_sbt;
         /// Total number of collections created
         uint256 total_collections_count;
+        /// Factory administrator
+        address owner;
+        /// Default metadata base URI that SBT collections may fall back to
+        string default_base_uri;
+        /// Whether symbols must be unique across all registered collections
+        bool enforce_unique_symbol;
+        /// Hashed symbols that have already been registered
+        mapping(bytes32 => bool) registered_symbols;
+        /// Position of each registered collection in `all_sbt_collections`
+        mapping(address => uint256) collection_index;
+        /// Nominated owner awaiting `accept_ownership`
+        address pending_owner;
+        /// Last timestamp at which `confirm_renounce` is accepted; zero when none is pending
+        uint256 renounce_deadline;
+        /// Issuer that registered each collection
+        mapping(address => address) collection_issuer;
+        /// Per-collection minters, mapped to the issuer who authorized them
+        mapping(address => mapping(address => address)) authorized_minters;
+        /// Reserved slots so future fields can be appended without shifting layout.
+        /// New fields go above this line; shrink the gap by the slots they use.
+        uint256[43] __gap;
     }
 
     pub struct SBTCollectionStorage {
@@ -42,10 +70,39 @@ sol! {
         string symbol,
     );
 
+    event SBTCollectionDeregistered(address indexed sbt_address);
+
+    event FactoryInitialized(address indexed owner);
+    event DefaultBaseUriUpdated(string base_uri);
+    event UniqueSymbolEnforcementUpdated(bool enabled);
+    event OwnershipTransferInitiated(address indexed previous_owner, address indexed new_owner);
+    event OwnershipTransferCancelled(address indexed owner, address indexed cancelled_owner);
+    event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+    event RenounceInitiated(address indexed owner, uint256 deadline);
+    event RenounceConfirmed(address indexed previous_owner);
+    event MinterAuthorized(address indexed sbt_address, address indexed minter);
+    event MinterRevoked(address indexed sbt_address, address indexed minter);
+
     // Errors
     error AddressZeroNotAllowed();
     error EmptyString();
     error ContractAlreadyRegistered();
+    error Unauthorized();
+    error SymbolTaken();
+    error CollectionNotRegistered();
+    error NotPendingOwner();
+    error RenounceNotInitiated();
+    error RenounceWindowExpired();
+    error CollectionCallFailed(address sbt_address);
+}
+
+sol! {
+    interface IEthosSBT {
+        function totalSupply() external view returns (uint256);
+        function getIssuer() external view returns (address);
+        function initialize(address issuer) external;
+        function factorySetIssuer(address new_issuer) external;
+    }
 }
 
 #[derive(SolidityError)]
@@ -53,9 +110,24 @@ pub enum SBTFactoryError {
     AddressZeroNotAllowed(AddressZeroNotAllowed),
     EmptyString(EmptyString),
     ContractAlreadyRegistered(ContractAlreadyRegistered),
+    Unauthorized(Unauthorized),
+    SymbolTaken(SymbolTaken),
+    CollectionNotRegistered(CollectionNotRegistered),
+    NotPendingOwner(NotPendingOwner),
+    RenounceNotInitiated(RenounceNotInitiated),
+    RenounceWindowExpired(RenounceWindowExpired),
+    CollectionCallFailed(CollectionCallFailed),
 }
 
 impl SBTFactory {
+    /// Internal function to restrict a call to the factory owner
+    fn only_owner(&self) -> Result<(), SBTFactoryError> {
+        if self.vm().msg_sender() != self.owner.get() {
+            return Err(SBTFactoryError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
     fn record_sbt_collection(
         &mut self,
         issuer: Address,
@@ -71,13 +143,20 @@ impl SBTFactory {
         new_collection.name.set_str(&name);
         new_collection.symbol.set_str(&symbol);
         new_collection.sbt_address.set(sbt_address);
+        self.collection_issuer.insert(sbt_address, issuer);
 
-        // Add to global list
+        // Add to global list and remember where it landed
+        let index = U256::from(self.all_sbt_collections.len());
         self.all_sbt_collections.push(sbt_address);
+        self.collection_index.insert(sbt_address, index);
 
         // Mark as valid SBT
         self.is_valid_sbt.insert(sbt_address, true);
 
+        // Track the symbol so uniqueness can be enforced later
+        self.registered_symbols
+            .insert(keccak(symbol.as_bytes()), true);
+
         // Increment total count
         let current_count = self.total_collections_count.get();
         self.total_collections_count
@@ -85,13 +164,99 @@ impl SBTFactory {
 
         Ok(())
     }
+
+    /// Internal function to read a collection's supply.
+    /// A failed or malformed call counts as zero.
+    fn read_total_supply(&self, sbt_address: Address) -> U256 {
+        let calldata = IEthosSBT::totalSupplyCall {}.abi_encode();
+        let Ok(returned) = self.vm().static_call(&Call::new(), sbt_address, &calldata) else {
+            return U256::ZERO;
+        };
+        IEthosSBT::totalSupplyCall::abi_decode_returns(&returned, true)
+            .map(|supply| supply._0)
+            .unwrap_or(U256::ZERO)
+    }
+
+    /// Internal function to call into a collection, failing if the collection reverts
+    fn call_collection(
+        &mut self,
+        sbt_address: Address,
+        calldata: Vec<u8>,
+    ) -> Result<(), SBTFactoryError> {
+        if self
+            .vm()
+            .call(&Call::new(), sbt_address, &calldata)
+            .is_err()
+        {
+            return Err(SBTFactoryError::CollectionCallFailed(
+                CollectionCallFailed { sbt_address },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Internal function to read a collection's issuer from the collection itself.
+    /// A failed or malformed call counts as no issuer.
+    fn read_issuer(&self, sbt_address: Address) -> Address {
+        let calldata = IEthosSBT::getIssuerCall {}.abi_encode();
+        let Ok(returned) = self.vm().static_call(&Call::new(), sbt_address, &calldata) else {
+            return Address::ZERO;
+        };
+        IEthosSBT::getIssuerCall::abi_decode_returns(&returned, true)
+            .map(|issuer| issuer._0)
+            .unwrap_or(Address::ZERO)
+    }
+
+    /// Internal function to restrict a call to the issuer of `sbt_address`. Whoever
+    /// registered it proves nothing, so the collection is asked for its issuer.
+    fn only_collection_issuer(&self, sbt_address: Address) -> Result<(), SBTFactoryError> {
+        if !self.is_valid_sbt.get(sbt_address) {
+            return Err(SBTFactoryError::CollectionNotRegistered(
+                CollectionNotRegistered {},
+            ));
+        }
+        let issuer = self.read_issuer(sbt_address);
+        if issuer.is_zero() || self.vm().msg_sender() != issuer {
+            return Err(SBTFactoryError::Unauthorized(Unauthorized {}));
+        }
+        Ok(())
+    }
+
+    /// Internal function to drop a collection from the global list in O(1).
+    /// The last entry is moved into the vacated position, so order is not preserved.
+    fn swap_remove_collection(&mut self, sbt_address: Address) {
+        let index = self.collection_index.get(sbt_address);
+        let last_index = self.all_sbt_collections.len() - 1;
+
+        if let Some(last) = self.all_sbt_collections.get(last_index) {
+            if let Some(mut slot) = self.all_sbt_collections.setter(index) {
+                slot.set(last);
+            }
+            self.collection_index.insert(last, index);
+        }
+
+        self.all_sbt_collections.pop();
+        self.collection_index.delete(sbt_address);
+    }
 }
 
 #[public]
 impl SBTFactory {
     #[constructor]
-    fn constructor(&mut self) {
+    fn constructor(&mut self) -> Result<(), SBTFactoryError> {
+        let owner = self.vm().tx_origin();
+
+        if owner.is_zero() {
+            return Err(SBTFactoryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.owner.set(owner);
         self.total_collections_count.set(U256::ZERO);
+
+        log(self.vm(), FactoryInitialized { owner });
+        Ok(())
     }
 
     /// Register an SBT collection
@@ -125,6 +290,12 @@ impl SBTFactory {
             ));
         }
 
+        if self.enforce_unique_symbol.get()
+            && self.registered_symbols.get(keccak(symbol.as_bytes()))
+        {
+            return Err(SBTFactoryError::SymbolTaken(SymbolTaken {}));
+        }
+
         self.record_sbt_collection(issuer, name.clone(), symbol.clone(), sbt_address)?;
 
         log(
@@ -139,6 +310,92 @@ impl SBTFactory {
         Ok(())
     }
 
+    /// Remove a collection from the factory so it is no longer treated as valid.
+    /// The issuer's own collection history is kept as-is.
+    fn deregister_sbt_collection(&mut self, sbt_address: Address) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+
+        if !self.is_valid_sbt.get(sbt_address) {
+            return Err(SBTFactoryError::CollectionNotRegistered(
+                CollectionNotRegistered {},
+            ));
+        }
+
+        self.swap_remove_collection(sbt_address);
+        self.is_valid_sbt.insert(sbt_address, false);
+
+        let current_count = self.total_collections_count.get();
+        self.total_collections_count
+            .set(current_count - U256::from(1));
+
+        log(self.vm(), SBTCollectionDeregistered { sbt_address });
+        Ok(())
+    }
+
+    /// Set the issuer of a registered collection that was deployed without one (e.g. via
+    /// CREATE2). Owner only: registration does not prove who deployed the collection, so
+    /// the registering account cannot be trusted to pick its issuer.
+    fn initialize_collection(
+        &mut self,
+        sbt_address: Address,
+        issuer: Address,
+    ) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+        if !self.is_valid_sbt.get(sbt_address) {
+            return Err(SBTFactoryError::CollectionNotRegistered(
+                CollectionNotRegistered {},
+            ));
+        }
+        if issuer.is_zero() {
+            return Err(SBTFactoryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.call_collection(
+            sbt_address,
+            IEthosSBT::initializeCall { issuer }.abi_encode(),
+        )
+    }
+
+    /// Hand a registered collection to `new_issuer` when its issuer key is lost
+    fn recover_collection_issuer(
+        &mut self,
+        sbt_address: Address,
+        new_issuer: Address,
+    ) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+        if !self.is_valid_sbt.get(sbt_address) {
+            return Err(SBTFactoryError::CollectionNotRegistered(
+                CollectionNotRegistered {},
+            ));
+        }
+        if new_issuer.is_zero() {
+            return Err(SBTFactoryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.call_collection(
+            sbt_address,
+            IEthosSBT::factorySetIssuerCall { new_issuer }.abi_encode(),
+        )
+    }
+
+    fn get_all_sbt_collections(&self) -> Vec<Address> {
+        let mut result = Vec::new();
+
+        for i in 0..self.all_sbt_collections.len() {
+            if let Some(sbt_address) = self.all_sbt_collections.get(i) {
+                result.push(sbt_address);
+            }
+        }
+
+        result
+    }
+
+    /// Every collection the issuer has registered. The result grows without bound,
+    /// so large issuers may exceed gas or RPC limits; use the paginated getter.
     fn get_issuer_collections(&self, issuer: Address) -> Vec<(String, String, Address)> {
         let storage_vec = self.issuer_collections.get(issuer);
         let mut result = Vec::new();
@@ -158,151 +415,494 @@ impl SBTFactory {
         result
     }
 
-    fn is_valid_sbt_contract(&self, sbt_address: Address) -> bool {
-        self.is_valid_sbt.get(sbt_address)
-    }
-
-    fn get_total_collections(&self) -> U256 {
-        self.total_collections_count.get()
-    }
-}
+    /// Up to `count` of the issuer's collections starting at `start`.
+    /// A window running past the end is cut short rather than reverting.
+    fn get_issuer_collections_paginated(
+        &self,
+        issuer: Address,
+        start: U256,
+        count: U256,
+    ) -> Vec<(String, String, Address)> {
+        let storage_vec = self.issuer_collections.get(issuer);
+        let len = U256::from(storage_vec.len());
+        let end = start.saturating_add(count).min(len);
+        let mut result = Vec::new();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloy_primitives::{address, Address, U256};
-    use stylus_sdk::testing::*;
+        if start >= end {
+            return result;
+        }
+        for i in start.to::<usize>()..end.to::<usize>() {
+            if let Some(collection) = storage_vec.get(i) {
+                result.push((
+                    collection.name.get_string(),
+                    collection.symbol.get_string(),
+                    collection.sbt_address.get(),
+                ));
+            }
+        }
 
-    fn setup_factory() -> (TestVM, SBTFactory) {
-        let vm = TestVM::default();
-        let mut factory = SBTFactory::from(&vm);
-        let _result = factory.constructor();
-        (vm, factory)
+        result
     }
 
-    #[test]
-    fn test_constructor_initialization() {
-        let (_vm, factory) = setup_factory();
-        assert_eq!(factory.get_total_collections(), U256::ZERO);
+    /// Number of tokens minted by a registered collection.
+    /// Unregistered addresses and collections whose call reverts report zero.
+    fn collection_supply(&self, sbt_address: Address) -> U256 {
+        if !self.is_valid_sbt.get(sbt_address) {
+            return U256::ZERO;
+        }
+        self.read_total_supply(sbt_address)
     }
 
-    #[test]
-    fn test_register_sbt_collection_success() {
-        let (vm, mut factory) = setup_factory();
-        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
-        let sbt_addr = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+    /// Tokens minted across all of an issuer's registered collections.
+    /// Deregistered collections are skipped, and a collection that was
+    /// re-registered is only counted once.
+    fn issuer_total_minted(&self, issuer: Address) -> U256 {
+        let storage_vec = self.issuer_collections.get(issuer);
+        let mut counted = Vec::new();
+        let mut total = U256::ZERO;
 
-        vm.set_sender(issuer);
-        let result =
-            factory.register_sbt_collection(sbt_addr, "Test SBT".to_string(), "TSBT".to_string());
+        for i in 0..storage_vec.len() {
+            if let Some(collection) = storage_vec.get(i) {
+                let sbt_address = collection.sbt_address.get();
+                if counted.contains(&sbt_address) {
+                    continue;
+                }
+                counted.push(sbt_address);
+                total = total.saturating_add(self.collection_supply(sbt_address));
+            }
+        }
 
-        assert!(result.is_ok());
-        assert_eq!(factory.get_total_collections(), U256::from(1));
-        assert!(factory.is_valid_sbt_contract(sbt_addr));
+        total
     }
 
-    #[test]
-    fn test_register_zero_sender() {
-        let (vm, mut factory) = setup_factory();
-        vm.set_sender(Address::ZERO);
-        let sbt_addr = address!("3333333333333333333333333333333333333333");
-        let name = "Test SBT".to_string();
-        let symbol = "TSBT".to_string();
+    /// Position of a registered collection in `get_all_sbt_collections`.
+    /// Positions change when another collection is deregistered.
+    fn index_of_collection(&self, sbt_address: Address) -> Result<U256, SBTFactoryError> {
+        if !self.is_valid_sbt.get(sbt_address) {
+            return Err(SBTFactoryError::CollectionNotRegistered(
+                CollectionNotRegistered {},
+            ));
+        }
+        Ok(self.collection_index.get(sbt_address))
+    }
 
-        let res = factory.register_sbt_collection(sbt_addr, name, symbol);
-        assert!(matches!(
-            res,
-            Err(SBTFactoryError::AddressZeroNotAllowed(_))
-        ));
+    fn is_valid_sbt_contract(&self, sbt_address: Address) -> bool {
+        self.is_valid_sbt.get(sbt_address)
     }
 
-    #[test]
-    fn test_register_multiple_collections_same_issuer() {
-        let (vm, mut factory) = setup_factory();
-        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
-        let sbt_addr1 = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
-        let sbt_addr2 = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+    /// Short alias of `is_valid_sbt_contract`
+    fn is_valid_sbt(&self, sbt_address: Address) -> bool {
+        self.is_valid_sbt_contract(sbt_address)
+    }
 
-        vm.set_sender(issuer);
+    /// Let `minter` mint on the collection's behalf; only the collection's issuer may call this
+    fn authorize_minter(
+        &mut self,
+        sbt_address: Address,
+        minter: Address,
+    ) -> Result<(), SBTFactoryError> {
+        self.only_collection_issuer(sbt_address)?;
+        if minter.is_zero() {
+            return Err(SBTFactoryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
 
-        // Register first collection
-        let result1 =
-            factory.register_sbt_collection(sbt_addr1, "First SBT".to_string(), "FSBT".to_string());
-        assert!(result1.is_ok());
+        let issuer = self.vm().msg_sender();
+        self.authorized_minters
+            .setter(sbt_address)
+            .insert(minter, issuer);
 
-        // Register second collection
-        let result2 = factory.register_sbt_collection(
-            sbt_addr2,
-            "Second SBT".to_string(),
-            "SSBT".to_string(),
+        log(
+            self.vm(),
+            MinterAuthorized {
+                sbt_address,
+                minter,
+            },
         );
-        assert!(result2.is_ok());
-
-        assert_eq!(factory.get_total_collections(), U256::from(2));
-        assert!(factory.is_valid_sbt_contract(sbt_addr1));
-        assert!(factory.is_valid_sbt_contract(sbt_addr2));
-
-        // Check issuer collections
-        let collections = factory.get_issuer_collections(issuer);
-        assert_eq!(collections.len(), 2);
-        assert_eq!(collections[0].0, "First SBT");
-        assert_eq!(collections[1].0, "Second SBT");
+        Ok(())
     }
 
-    #[test]
-    fn test_register_multiple_issuers() {
-        let (vm, mut factory) = setup_factory();
-        let issuer1 = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
-        let issuer2 = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
-        let sbt_addr1 = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
-        let sbt_addr2 = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+    fn revoke_minter(
+        &mut self,
+        sbt_address: Address,
+        minter: Address,
+    ) -> Result<(), SBTFactoryError> {
+        self.only_collection_issuer(sbt_address)?;
 
-        // Register from first issuer
-        vm.set_sender(issuer1);
-        let result1 = factory.register_sbt_collection(
-            sbt_addr1,
-            "Issuer1 SBT".to_string(),
-            "I1SBT".to_string(),
-        );
-        assert!(result1.is_ok());
+        self.authorized_minters.setter(sbt_address).delete(minter);
 
-        // Register from second issuer
-        vm.set_sender(issuer2);
-        let result2 = factory.register_sbt_collection(
-            sbt_addr2,
-            "Issuer2 SBT".to_string(),
-            "I2SBT".to_string(),
+        log(
+            self.vm(),
+            MinterRevoked {
+                sbt_address,
+                minter,
+            },
         );
-        assert!(result2.is_ok());
-
-        assert_eq!(factory.get_total_collections(), U256::from(2));
+        Ok(())
+    }
 
-        // Check each issuer's collections
-        let collections1 = factory.get_issuer_collections(issuer1);
-        let collections2 = factory.get_issuer_collections(issuer2);
+    /// Whether `minter` may mint for a registered collection. Only grants made by
+    /// the collection's current issuer count, so they lapse if the issuer changes.
+    fn is_authorized_minter(&self, sbt_address: Address, minter: Address) -> bool {
+        let granted_by = self.authorized_minters.getter(sbt_address).get(minter);
+        self.is_valid_sbt.get(sbt_address)
+            && !granted_by.is_zero()
+            && granted_by == self.read_issuer(sbt_address)
+    }
 
-        assert_eq!(collections1.len(), 1);
-        assert_eq!(collections2.len(), 1);
-        assert_eq!(collections1[0].0, "Issuer1 SBT");
-        assert_eq!(collections2[0].0, "Issuer2 SBT");
+    /// Issuer that registered the collection, or zero if it never was
+    fn get_collection_issuer(&self, sbt_address: Address) -> Address {
+        self.collection_issuer.get(sbt_address)
     }
 
-    #[test]
-    fn test_register_zero_sbt_address() {
-        let (vm, mut factory) = setup_factory();
-        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+    fn get_total_collections(&self) -> U256 {
+        self.total_collections_count.get()
+    }
 
-        vm.set_sender(issuer);
-        let result = factory.register_sbt_collection(
-            Address::ZERO,
-            "Test SBT".to_string(),
-            "TSBT".to_string(),
-        );
+    fn get_owner(&self) -> Address {
+        self.owner.get()
+    }
 
-        assert!(matches!(
-            result,
-            Err(SBTFactoryError::AddressZeroNotAllowed(_))
-        ));
+    fn get_pending_owner(&self) -> Address {
+        self.pending_owner.get()
+    }
+
+    /// First step of giving up ownership for good. `confirm_renounce` must follow
+    /// within `RENOUNCE_WINDOW` seconds; calling this again restarts the window.
+    fn initiate_renounce(&mut self) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+
+        let deadline = U256::from(self.vm().block_timestamp()) + U256::from(RENOUNCE_WINDOW);
+        self.renounce_deadline.set(deadline);
+
+        log(
+            self.vm(),
+            RenounceInitiated {
+                owner: self.owner.get(),
+                deadline,
+            },
+        );
+        Ok(())
+    }
+
+    /// Clear the owner permanently. Admin functions are unusable afterwards.
+    fn confirm_renounce(&mut self) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+
+        let deadline = self.renounce_deadline.get();
+        if deadline.is_zero() {
+            return Err(SBTFactoryError::RenounceNotInitiated(
+                RenounceNotInitiated {},
+            ));
+        }
+        if U256::from(self.vm().block_timestamp()) > deadline {
+            return Err(SBTFactoryError::RenounceWindowExpired(
+                RenounceWindowExpired {},
+            ));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(Address::ZERO);
+        self.pending_owner.set(Address::ZERO);
+        self.renounce_deadline.set(U256::ZERO);
+
+        log(self.vm(), RenounceConfirmed { previous_owner });
+        log(
+            self.vm(),
+            OwnershipTransferred {
+                previous_owner,
+                new_owner: Address::ZERO,
+            },
+        );
+        Ok(())
+    }
+
+    fn get_renounce_deadline(&self) -> U256 {
+        self.renounce_deadline.get()
+    }
+
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+
+        if new_owner.is_zero() {
+            return Err(SBTFactoryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        self.pending_owner.set(new_owner);
+
+        log(
+            self.vm(),
+            OwnershipTransferInitiated {
+                previous_owner: self.owner.get(),
+                new_owner,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw a pending ownership transfer before it is accepted
+    fn cancel_ownership_transfer(&mut self) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+
+        let cancelled_owner = self.pending_owner.get();
+        if cancelled_owner.is_zero() {
+            return Err(SBTFactoryError::NotPendingOwner(NotPendingOwner {}));
+        }
+
+        self.pending_owner.set(Address::ZERO);
+
+        log(
+            self.vm(),
+            OwnershipTransferCancelled {
+                owner: self.owner.get(),
+                cancelled_owner,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), SBTFactoryError> {
+        let caller = self.vm().msg_sender();
+        let pending_owner = self.pending_owner.get();
+
+        if caller.is_zero() {
+            return Err(SBTFactoryError::AddressZeroNotAllowed(
+                AddressZeroNotAllowed {},
+            ));
+        }
+
+        if pending_owner != caller {
+            return Err(SBTFactoryError::NotPendingOwner(NotPendingOwner {}));
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(pending_owner);
+        self.pending_owner.set(Address::ZERO);
+        self.renounce_deadline.set(U256::ZERO);
+
+        log(
+            self.vm(),
+            OwnershipTransferred {
+                previous_owner,
+                new_owner: pending_owner,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Set the base URI that SBT collections fall back to for token metadata
+    fn set_default_base_uri(&mut self, base_uri: String) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+
+        if base_uri.is_empty() {
+            return Err(SBTFactoryError::EmptyString(EmptyString {}));
+        }
+
+        self.default_base_uri.set_str(&base_uri);
+
+        log(self.vm(), DefaultBaseUriUpdated { base_uri });
+        Ok(())
+    }
+
+    fn default_base_uri(&self) -> String {
+        self.default_base_uri.get_string()
+    }
+
+    /// Toggle whether newly registered collections must use a symbol nobody has registered yet
+    fn set_enforce_unique_symbol(&mut self, enabled: bool) -> Result<(), SBTFactoryError> {
+        self.only_owner()?;
+
+        self.enforce_unique_symbol.set(enabled);
+
+        log(self.vm(), UniqueSymbolEnforcementUpdated { enabled });
+        Ok(())
+    }
+
+    fn enforce_unique_symbol(&self) -> bool {
+        self.enforce_unique_symbol.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, Address, B256, U256};
+    use alloy_sol_types::SolEvent;
+    use stylus_sdk::testing::*;
+
+    /// First slot past the factory layout (end of the gap); adding fields must not move it
+    const FACTORY_STORAGE_END: u64 = 56;
+
+    fn setup_factory() -> (TestVM, SBTFactory) {
+        let vm = TestVM::default();
+        let mut factory = SBTFactory::from(&vm);
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(owner);
+        let result = factory.constructor();
+        assert!(result.is_ok());
+
+        (vm, factory)
+    }
+
+    #[test]
+    fn test_constructor_initialization() {
+        let (_vm, factory) = setup_factory();
+        assert_eq!(factory.get_total_collections(), U256::ZERO);
+        assert_eq!(
+            factory.get_owner(),
+            address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720")
+        );
+    }
+
+    #[test]
+    fn test_constructor_emits_initialized() {
+        let (vm, _factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 1);
+        let (topics, data) = &logs[0];
+        let event = FactoryInitialized::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(event, Ok(FactoryInitialized { owner: logged }) if logged == owner));
+    }
+
+    #[test]
+    fn test_constructor_with_zero_address() {
+        let vm = TestVM::default();
+        let mut factory = SBTFactory::from(&vm);
+
+        vm.set_sender(Address::ZERO);
+        let result = factory.constructor();
+        assert!(matches!(
+            result,
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_register_sbt_collection_success() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let sbt_addr = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(issuer);
+        let result =
+            factory.register_sbt_collection(sbt_addr, "Test SBT".to_string(), "TSBT".to_string());
+
+        assert!(result.is_ok());
+        assert_eq!(factory.get_total_collections(), U256::from(1));
+        assert!(factory.is_valid_sbt_contract(sbt_addr));
+    }
+
+    #[test]
+    fn test_register_zero_sender() {
+        let (vm, mut factory) = setup_factory();
+        vm.set_sender(Address::ZERO);
+        let sbt_addr = address!("3333333333333333333333333333333333333333");
+        let name = "Test SBT".to_string();
+        let symbol = "TSBT".to_string();
+
+        let res = factory.register_sbt_collection(sbt_addr, name, symbol);
+        assert!(matches!(
+            res,
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_register_multiple_collections_same_issuer() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let sbt_addr1 = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let sbt_addr2 = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+
+        vm.set_sender(issuer);
+
+        // Register first collection
+        let result1 =
+            factory.register_sbt_collection(sbt_addr1, "First SBT".to_string(), "FSBT".to_string());
+        assert!(result1.is_ok());
+
+        // Register second collection
+        let result2 = factory.register_sbt_collection(
+            sbt_addr2,
+            "Second SBT".to_string(),
+            "SSBT".to_string(),
+        );
+        assert!(result2.is_ok());
+
+        assert_eq!(factory.get_total_collections(), U256::from(2));
+        assert!(factory.is_valid_sbt_contract(sbt_addr1));
+        assert!(factory.is_valid_sbt_contract(sbt_addr2));
+
+        // Check issuer collections
+        let collections = factory.get_issuer_collections(issuer);
+        assert_eq!(collections.len(), 2);
+        assert_eq!(collections[0].0, "First SBT");
+        assert_eq!(collections[1].0, "Second SBT");
+    }
+
+    #[test]
+    fn test_register_multiple_issuers() {
+        let (vm, mut factory) = setup_factory();
+        let issuer1 = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let issuer2 = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let sbt_addr1 = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let sbt_addr2 = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+
+        // Register from first issuer
+        vm.set_sender(issuer1);
+        let result1 = factory.register_sbt_collection(
+            sbt_addr1,
+            "Issuer1 SBT".to_string(),
+            "I1SBT".to_string(),
+        );
+        assert!(result1.is_ok());
+
+        // Register from second issuer
+        vm.set_sender(issuer2);
+        let result2 = factory.register_sbt_collection(
+            sbt_addr2,
+            "Issuer2 SBT".to_string(),
+            "I2SBT".to_string(),
+        );
+        assert!(result2.is_ok());
+
+        assert_eq!(factory.get_total_collections(), U256::from(2));
+
+        // Check each issuer's collections
+        let collections1 = factory.get_issuer_collections(issuer1);
+        let collections2 = factory.get_issuer_collections(issuer2);
+
+        assert_eq!(collections1.len(), 1);
+        assert_eq!(collections2.len(), 1);
+        assert_eq!(collections1[0].0, "Issuer1 SBT");
+        assert_eq!(collections2[0].0, "Issuer2 SBT");
+    }
+
+    #[test]
+    fn test_register_zero_sbt_address() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        let result = factory.register_sbt_collection(
+            Address::ZERO,
+            "Test SBT".to_string(),
+            "TSBT".to_string(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
     }
 
     #[test]
@@ -397,6 +997,7 @@ mod tests {
         let random_addr = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
 
         assert!(!factory.is_valid_sbt_contract(random_addr));
+        assert!(!factory.is_valid_sbt(random_addr));
     }
 
     #[test]
@@ -410,6 +1011,7 @@ mod tests {
             factory.register_sbt_collection(sbt_addr, "Test SBT".to_string(), "TSBT".to_string());
 
         assert!(factory.is_valid_sbt_contract(sbt_addr));
+        assert!(factory.is_valid_sbt(sbt_addr));
     }
 
     #[test]
@@ -479,4 +1081,1053 @@ mod tests {
         assert_eq!(collections[0].0, long_name);
         assert_eq!(collections[0].1, long_symbol);
     }
+
+    // DEFAULT BASE URI TESTS
+
+    #[test]
+    fn test_set_default_base_uri_success() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        assert_eq!(factory.default_base_uri(), "");
+
+        vm.set_sender(owner);
+        let result = factory.set_default_base_uri("https://meta.ethos.xyz/".to_string());
+
+        assert!(result.is_ok());
+        assert_eq!(factory.default_base_uri(), "https://meta.ethos.xyz/");
+    }
+
+    #[test]
+    fn test_set_default_base_uri_unauthorized() {
+        let (vm, mut factory) = setup_factory();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        let result = factory.set_default_base_uri("https://meta.ethos.xyz/".to_string());
+
+        assert!(matches!(result, Err(SBTFactoryError::Unauthorized(_))));
+        assert_eq!(factory.default_base_uri(), "");
+    }
+
+    #[test]
+    fn test_set_default_base_uri_empty() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(owner);
+        let result = factory.set_default_base_uri(String::new());
+
+        assert!(matches!(result, Err(SBTFactoryError::EmptyString(_))));
+    }
+
+    #[test]
+    fn test_storage_layout_with_gap() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let sbt_addr = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(issuer);
+        assert!(factory
+            .register_sbt_collection(sbt_addr, "Test SBT".to_string(), "TSBT".to_string())
+            .is_ok());
+
+        // Existing fields still read back and the gap stays untouched
+        assert!(factory.is_valid_sbt_contract(sbt_addr));
+        assert_eq!(factory.get_total_collections(), U256::from(1));
+        for i in 0..factory.__gap.len() {
+            assert_eq!(factory.__gap.get(i), Some(U256::ZERO));
+        }
+
+        // The last gap slot sits right before the fixed end of the layout
+        let sentinel = U256::from(0xe7405);
+        let last = factory.__gap.len() - 1;
+        if let Some(mut slot) = factory.__gap.setter(last) {
+            slot.set(sentinel);
+        }
+        assert_eq!(
+            vm.get_storage(U256::from(FACTORY_STORAGE_END - 1)),
+            B256::from(sentinel)
+        );
+    }
+
+    // UNIQUE SYMBOL TESTS
+
+    #[test]
+    fn test_duplicate_symbol_allowed_when_not_enforced() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        assert!(!factory.enforce_unique_symbol());
+
+        vm.set_sender(issuer);
+        let result1 = factory.register_sbt_collection(
+            address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"),
+            "First SBT".to_string(),
+            "SAME".to_string(),
+        );
+        assert!(result1.is_ok());
+
+        let result2 = factory.register_sbt_collection(
+            address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC"),
+            "Second SBT".to_string(),
+            "SAME".to_string(),
+        );
+        assert!(result2.is_ok());
+        assert_eq!(factory.get_total_collections(), U256::from(2));
+    }
+
+    #[test]
+    fn test_duplicate_symbol_rejected_when_enforced() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer1 = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let issuer2 = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+
+        vm.set_sender(owner);
+        assert!(factory.set_enforce_unique_symbol(true).is_ok());
+        assert!(factory.enforce_unique_symbol());
+
+        vm.set_sender(issuer1);
+        let result1 = factory.register_sbt_collection(
+            address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"),
+            "First SBT".to_string(),
+            "SAME".to_string(),
+        );
+        assert!(result1.is_ok());
+
+        // Another issuer cannot reuse the symbol
+        vm.set_sender(issuer2);
+        let result2 = factory.register_sbt_collection(
+            address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC"),
+            "Impostor SBT".to_string(),
+            "SAME".to_string(),
+        );
+        assert!(matches!(result2, Err(SBTFactoryError::SymbolTaken(_))));
+
+        // A different symbol is still fine
+        let result3 = factory.register_sbt_collection(
+            address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC"),
+            "Other SBT".to_string(),
+            "OTHER".to_string(),
+        );
+        assert!(result3.is_ok());
+        assert_eq!(factory.get_total_collections(), U256::from(2));
+    }
+
+    #[test]
+    fn test_symbols_registered_before_enforcement_are_taken() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        vm.set_sender(issuer);
+        assert!(factory
+            .register_sbt_collection(
+                address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"),
+                "First SBT".to_string(),
+                "SAME".to_string(),
+            )
+            .is_ok());
+
+        vm.set_sender(owner);
+        assert!(factory.set_enforce_unique_symbol(true).is_ok());
+
+        vm.set_sender(issuer);
+        let result = factory.register_sbt_collection(
+            address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC"),
+            "Second SBT".to_string(),
+            "SAME".to_string(),
+        );
+        assert!(matches!(result, Err(SBTFactoryError::SymbolTaken(_))));
+    }
+
+    #[test]
+    fn test_set_enforce_unique_symbol_unauthorized() {
+        let (vm, mut factory) = setup_factory();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        let result = factory.set_enforce_unique_symbol(true);
+
+        assert!(matches!(result, Err(SBTFactoryError::Unauthorized(_))));
+        assert!(!factory.enforce_unique_symbol());
+    }
+
+    // DEREGISTRATION TESTS
+
+    /// The global list, validity map, index map and count must always agree
+    fn assert_collection_invariants(factory: &SBTFactory) {
+        let collections = factory.get_all_sbt_collections();
+        assert_eq!(
+            U256::from(collections.len()),
+            factory.get_total_collections()
+        );
+
+        for (i, sbt_address) in collections.iter().enumerate() {
+            assert!(factory.is_valid_sbt_contract(*sbt_address));
+            assert_eq!(factory.collection_index.get(*sbt_address), U256::from(i));
+        }
+    }
+
+    fn register_three(vm: &TestVM, factory: &mut SBTFactory) -> [Address; 3] {
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let collections = [
+            address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"),
+            address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC"),
+            address!("90F79bf6EB2c4f870365E785982E1f101E93b906"),
+        ];
+
+        vm.set_sender(issuer);
+        for (i, sbt_addr) in collections.iter().enumerate() {
+            let result = factory.register_sbt_collection(
+                *sbt_addr,
+                format!("SBT {}", i),
+                format!("SBT{}", i),
+            );
+            assert!(result.is_ok());
+        }
+
+        collections
+    }
+
+    #[test]
+    fn test_deregister_middle_collection_swap_removes() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let [first, middle, last] = register_three(&vm, &mut factory);
+        assert_eq!(factory.get_all_sbt_collections(), vec![first, middle, last]);
+
+        vm.set_sender(owner);
+        assert!(factory.deregister_sbt_collection(middle).is_ok());
+        assert_collection_invariants(&factory);
+
+        // The last entry fills the hole, leaving exactly the two valid addresses
+        assert_eq!(factory.get_all_sbt_collections(), vec![first, last]);
+        assert!(!factory.is_valid_sbt_contract(middle));
+        assert!(factory.is_valid_sbt_contract(first));
+        assert!(factory.is_valid_sbt_contract(last));
+        assert_eq!(factory.get_total_collections(), U256::from(2));
+        assert_eq!(factory.collection_index.get(last), U256::from(1));
+    }
+
+    #[test]
+    fn test_index_of_collection_tracks_swap_remove() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let [first, middle, last] = register_three(&vm, &mut factory);
+
+        for (i, sbt_address) in [first, middle, last].iter().enumerate() {
+            assert!(matches!(
+                factory.index_of_collection(*sbt_address),
+                Ok(index) if index == U256::from(i)
+            ));
+        }
+
+        vm.set_sender(owner);
+        assert!(factory.deregister_sbt_collection(first).is_ok());
+
+        // `last` moved into the vacated slot; `middle` did not move
+        assert!(matches!(factory.index_of_collection(last), Ok(index) if index == U256::ZERO));
+        assert!(matches!(factory.index_of_collection(middle), Ok(index) if index == U256::from(1)));
+        assert!(matches!(
+            factory.index_of_collection(first),
+            Err(SBTFactoryError::CollectionNotRegistered(_))
+        ));
+
+        let all = factory.get_all_sbt_collections();
+        for sbt_address in all.iter() {
+            assert!(matches!(
+                factory.index_of_collection(*sbt_address),
+                Ok(index) if all[index.to::<usize>()] == *sbt_address
+            ));
+        }
+    }
+
+    #[test]
+    fn test_index_of_collection_unknown() {
+        let (_vm, factory) = setup_factory();
+        let sbt_addr = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        assert!(matches!(
+            factory.index_of_collection(sbt_addr),
+            Err(SBTFactoryError::CollectionNotRegistered(_))
+        ));
+    }
+
+    #[test]
+    fn test_deregister_last_collection() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let [first, middle, last] = register_three(&vm, &mut factory);
+
+        vm.set_sender(owner);
+        assert!(factory.deregister_sbt_collection(last).is_ok());
+        assert_eq!(factory.get_all_sbt_collections(), vec![first, middle]);
+
+        // Removing everything leaves an empty list
+        assert!(factory.deregister_sbt_collection(first).is_ok());
+        assert!(factory.deregister_sbt_collection(middle).is_ok());
+        assert!(factory.get_all_sbt_collections().is_empty());
+        assert_eq!(factory.get_total_collections(), U256::ZERO);
+        assert_collection_invariants(&factory);
+    }
+
+    #[test]
+    fn test_deregistered_collection_can_register_again() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let [first, middle, last] = register_three(&vm, &mut factory);
+
+        vm.set_sender(owner);
+        assert!(factory.deregister_sbt_collection(first).is_ok());
+
+        vm.set_sender(issuer);
+        assert!(factory
+            .register_sbt_collection(first, "Again".to_string(), "AGAIN".to_string())
+            .is_ok());
+        assert_eq!(factory.get_all_sbt_collections(), vec![last, middle, first]);
+        assert_eq!(factory.collection_index.get(first), U256::from(2));
+    }
+
+    #[test]
+    fn test_deregister_emits_event() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let [_, middle, _] = register_three(&vm, &mut factory);
+
+        vm.set_sender(owner);
+        assert!(factory.deregister_sbt_collection(middle).is_ok());
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = SBTCollectionDeregistered::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(SBTCollectionDeregistered { sbt_address }) if sbt_address == middle
+        ));
+    }
+
+    #[test]
+    fn test_random_register_deregister_keeps_invariants() {
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        for seed in 1..=8u64 {
+            let (vm, mut factory) = setup_factory();
+            let mut state = seed;
+            let mut registered: Vec<Address> = Vec::new();
+
+            for _ in 0..60 {
+                // xorshift keeps the sequence deterministic per seed
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+
+                let sbt_addr = Address::with_last_byte((state % 16) as u8 + 1);
+                if state % 3 == 0 && !registered.is_empty() {
+                    let victim = registered.remove((state as usize / 3) % registered.len());
+                    vm.set_sender(owner);
+                    assert!(factory.deregister_sbt_collection(victim).is_ok());
+                } else {
+                    vm.set_sender(issuer);
+                    let result = factory.register_sbt_collection(
+                        sbt_addr,
+                        "Fuzz SBT".to_string(),
+                        "FUZZ".to_string(),
+                    );
+                    assert_eq!(result.is_ok(), !registered.contains(&sbt_addr));
+                    if result.is_ok() {
+                        registered.push(sbt_addr);
+                    }
+                }
+
+                assert_collection_invariants(&factory);
+                assert_eq!(
+                    factory.get_total_collections(),
+                    U256::from(registered.len())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_deregister_unknown_collection() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let sbt_addr = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        let result = factory.deregister_sbt_collection(sbt_addr);
+        assert!(matches!(
+            result,
+            Err(SBTFactoryError::CollectionNotRegistered(_))
+        ));
+    }
+
+    #[test]
+    fn test_deregister_unauthorized() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let [first, _, _] = register_three(&vm, &mut factory);
+
+        vm.set_sender(issuer);
+        let result = factory.deregister_sbt_collection(first);
+        assert!(matches!(result, Err(SBTFactoryError::Unauthorized(_))));
+        assert!(factory.is_valid_sbt_contract(first));
+    }
+
+    #[test]
+    fn test_get_issuer_collections_paginated_windows() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let [first, second, third] = register_three(&vm, &mut factory);
+
+        let page = |start: u64, count: u64| {
+            factory
+                .get_issuer_collections_paginated(issuer, U256::from(start), U256::from(count))
+                .into_iter()
+                .map(|(_, _, sbt_address)| sbt_address)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(page(0, 2), vec![first, second]);
+        assert_eq!(page(2, 2), vec![third]);
+        assert_eq!(page(1, 1), vec![second]);
+        assert_eq!(page(0, 10), vec![first, second, third]);
+
+        let full = factory.get_issuer_collections_paginated(issuer, U256::ZERO, U256::from(3));
+        assert_eq!(full, factory.get_issuer_collections(issuer));
+        assert_eq!(full[1].0, "SBT 1");
+        assert_eq!(full[1].1, "SBT1");
+    }
+
+    #[test]
+    fn test_get_issuer_collections_paginated_past_end() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        register_three(&vm, &mut factory);
+
+        assert!(factory
+            .get_issuer_collections_paginated(issuer, U256::from(3), U256::from(5))
+            .is_empty());
+        assert!(factory
+            .get_issuer_collections_paginated(issuer, U256::from(1), U256::ZERO)
+            .is_empty());
+        assert_eq!(
+            factory
+                .get_issuer_collections_paginated(issuer, U256::from(1), U256::MAX)
+                .len(),
+            2
+        );
+        assert!(factory
+            .get_issuer_collections_paginated(issuer, U256::MAX, U256::MAX)
+            .is_empty());
+        assert!(factory
+            .get_issuer_collections_paginated(Address::ZERO, U256::ZERO, U256::from(5))
+            .is_empty());
+    }
+
+    // SUPPLY STATISTICS TESTS
+
+    fn mock_supply(vm: &TestVM, sbt_address: Address, supply: U256) {
+        vm.mock_static_call(
+            sbt_address,
+            IEthosSBT::totalSupplyCall {}.abi_encode(),
+            Ok(IEthosSBT::totalSupplyCall::abi_encode_returns(&(supply,))),
+        );
+    }
+
+    #[test]
+    fn test_collection_supply_reads_sbt() {
+        let (vm, mut factory) = setup_factory();
+        let [first, second, third] = register_three(&vm, &mut factory);
+
+        mock_supply(&vm, first, U256::from(7));
+        mock_supply(&vm, second, U256::from(35));
+
+        assert_eq!(factory.collection_supply(first), U256::from(7));
+        assert_eq!(factory.collection_supply(second), U256::from(35));
+        // No mocked response behaves like a reverting SBT
+        assert_eq!(factory.collection_supply(third), U256::ZERO);
+    }
+
+    #[test]
+    fn test_collection_supply_unregistered_is_zero() {
+        let (vm, factory) = setup_factory();
+        let sbt_addr = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        mock_supply(&vm, sbt_addr, U256::from(5));
+        assert_eq!(factory.collection_supply(sbt_addr), U256::ZERO);
+    }
+
+    #[test]
+    fn test_issuer_total_minted_sums_collections() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let other_issuer = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+        let sbt_addr1 = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let sbt_addr2 = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+        let sbt_addr3 = address!("15d34AAf54267DB7D7c367839AAf71A00a2C6A65");
+
+        vm.set_sender(issuer);
+        assert!(factory
+            .register_sbt_collection(sbt_addr1, "First SBT".to_string(), "FSBT".to_string())
+            .is_ok());
+        assert!(factory
+            .register_sbt_collection(sbt_addr2, "Second SBT".to_string(), "SSBT".to_string())
+            .is_ok());
+        vm.set_sender(other_issuer);
+        assert!(factory
+            .register_sbt_collection(sbt_addr3, "Third SBT".to_string(), "TSBT".to_string())
+            .is_ok());
+
+        mock_supply(&vm, sbt_addr1, U256::from(12));
+        mock_supply(&vm, sbt_addr2, U256::from(30));
+        mock_supply(&vm, sbt_addr3, U256::from(100));
+
+        assert_eq!(factory.issuer_total_minted(issuer), U256::from(42));
+        assert_eq!(factory.issuer_total_minted(other_issuer), U256::from(100));
+        assert_eq!(
+            factory.issuer_total_minted(address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720")),
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn test_issuer_total_minted_skips_deregistered_and_duplicates() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let [first, second, third] = register_three(&vm, &mut factory);
+
+        mock_supply(&vm, first, U256::from(1));
+        mock_supply(&vm, second, U256::from(10));
+        mock_supply(&vm, third, U256::from(100));
+        assert_eq!(factory.issuer_total_minted(issuer), U256::from(111));
+
+        vm.set_sender(owner);
+        assert!(factory.deregister_sbt_collection(second).is_ok());
+        assert_eq!(factory.issuer_total_minted(issuer), U256::from(101));
+
+        // Registering the same collection again adds a second history entry
+        vm.set_sender(issuer);
+        assert!(factory
+            .register_sbt_collection(second, "Again".to_string(), "AGN".to_string())
+            .is_ok());
+        assert_eq!(factory.get_issuer_collections(issuer).len(), 4);
+        assert_eq!(factory.issuer_total_minted(issuer), U256::from(111));
+    }
+
+    // OWNERSHIP TESTS
+
+    #[test]
+    fn test_transfer_ownership_success() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        let result = factory.transfer_ownership(alice);
+
+        assert!(result.is_ok());
+        assert_eq!(factory.get_pending_owner(), alice);
+        // Owner should remain unchanged until accepted
+        assert_eq!(factory.get_owner(), owner);
+    }
+
+    #[test]
+    fn test_transfer_ownership_unauthorized() {
+        let (vm, mut factory) = setup_factory();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+
+        vm.set_sender(alice);
+        let result = factory.transfer_ownership(bob);
+
+        assert!(matches!(result, Err(SBTFactoryError::Unauthorized(_))));
+        assert_eq!(factory.get_pending_owner(), Address::ZERO);
+    }
+
+    #[test]
+    fn test_transfer_ownership_to_zero_address() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(owner);
+        let result = factory.transfer_ownership(Address::ZERO);
+
+        assert!(matches!(
+            result,
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_accept_ownership_success() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(factory.transfer_ownership(alice).is_ok());
+
+        vm.set_sender(alice);
+        let result = factory.accept_ownership();
+
+        assert!(result.is_ok());
+        assert_eq!(factory.get_owner(), alice);
+        assert_eq!(factory.get_pending_owner(), Address::ZERO);
+
+        // The new owner holds the admin controls, the old one no longer does
+        assert!(factory.set_enforce_unique_symbol(true).is_ok());
+        vm.set_sender(owner);
+        assert!(matches!(
+            factory.set_enforce_unique_symbol(false),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_accept_ownership_not_pending_owner() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+        let bob = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+
+        vm.set_sender(owner);
+        assert!(factory.transfer_ownership(alice).is_ok());
+
+        vm.set_sender(bob);
+        let result = factory.accept_ownership();
+
+        assert!(matches!(result, Err(SBTFactoryError::NotPendingOwner(_))));
+        assert_eq!(factory.get_owner(), owner);
+    }
+
+    #[test]
+    fn test_accept_ownership_no_pending_transfer() {
+        let (vm, mut factory) = setup_factory();
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        let result = factory.accept_ownership();
+
+        assert!(matches!(result, Err(SBTFactoryError::NotPendingOwner(_))));
+    }
+
+    #[test]
+    fn test_accept_ownership_zero_address() {
+        let (vm, mut factory) = setup_factory();
+
+        vm.set_sender(Address::ZERO);
+        let result = factory.accept_ownership();
+
+        assert!(matches!(
+            result,
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_pending_owner_tracks_transfer_lifecycle() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(factory.transfer_ownership(alice).is_ok());
+        assert_eq!(factory.get_pending_owner(), alice);
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = OwnershipTransferInitiated::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(OwnershipTransferInitiated { previous_owner, new_owner })
+                if previous_owner == owner && new_owner == alice
+        ));
+
+        vm.set_sender(alice);
+        assert!(factory.accept_ownership().is_ok());
+        assert_eq!(factory.get_pending_owner(), Address::ZERO);
+    }
+
+    #[test]
+    fn test_cancel_ownership_transfer() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(owner);
+        assert!(factory.transfer_ownership(alice).is_ok());
+        assert!(factory.cancel_ownership_transfer().is_ok());
+        assert_eq!(factory.get_pending_owner(), Address::ZERO);
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = OwnershipTransferCancelled::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(OwnershipTransferCancelled { owner: emitted_owner, cancelled_owner })
+                if emitted_owner == owner && cancelled_owner == alice
+        ));
+
+        // The cancelled target can no longer accept
+        vm.set_sender(alice);
+        assert!(matches!(
+            factory.accept_ownership(),
+            Err(SBTFactoryError::NotPendingOwner(_))
+        ));
+        assert_eq!(factory.get_owner(), owner);
+    }
+
+    #[test]
+    fn test_cancel_ownership_transfer_guards() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        // Nothing pending yet
+        vm.set_sender(owner);
+        assert!(matches!(
+            factory.cancel_ownership_transfer(),
+            Err(SBTFactoryError::NotPendingOwner(_))
+        ));
+
+        assert!(factory.transfer_ownership(alice).is_ok());
+        vm.set_sender(alice);
+        assert!(matches!(
+            factory.cancel_ownership_transfer(),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+        assert_eq!(factory.get_pending_owner(), alice);
+    }
+
+    // MINTER TESTS
+
+    fn mock_issuer(vm: &TestVM, sbt_address: Address, issuer: Address) {
+        vm.mock_static_call(
+            sbt_address,
+            IEthosSBT::getIssuerCall {}.abi_encode(),
+            Ok(IEthosSBT::getIssuerCall::abi_encode_returns(&(issuer,))),
+        );
+    }
+
+    #[test]
+    fn test_authorize_and_revoke_minter() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let minter = address!("15d34AAf54267DB7D7c367839AAf71A00a2C6A65");
+        let [first, second, _] = register_three(&vm, &mut factory);
+        mock_issuer(&vm, first, issuer);
+        mock_issuer(&vm, second, issuer);
+
+        assert_eq!(factory.get_collection_issuer(first), issuer);
+        assert!(!factory.is_authorized_minter(first, minter));
+
+        assert!(factory.authorize_minter(first, minter).is_ok());
+        assert!(factory.is_authorized_minter(first, minter));
+        // Scoped to the one collection
+        assert!(!factory.is_authorized_minter(second, minter));
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = MinterAuthorized::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(MinterAuthorized { sbt_address, minter: m }) if sbt_address == first && m == minter
+        ));
+
+        assert!(factory.revoke_minter(first, minter).is_ok());
+        assert!(!factory.is_authorized_minter(first, minter));
+
+        let logs = vm.get_emitted_logs();
+        let (topics, data) = &logs[logs.len() - 1];
+        let event = MinterRevoked::decode_raw_log(topics.iter().copied(), data, true);
+        assert!(matches!(
+            event,
+            Ok(MinterRevoked { sbt_address, minter: m }) if sbt_address == first && m == minter
+        ));
+    }
+
+    #[test]
+    fn test_minter_management_is_issuer_only() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let other_issuer = address!("9965507D1a55bcC2695C58ba16FB37d819B0A4dc");
+        let minter = address!("15d34AAf54267DB7D7c367839AAf71A00a2C6A65");
+        let unknown = address!("976EA74026E726554dB657fA54763abd0C3a0aa9");
+        let [first, _, _] = register_three(&vm, &mut factory);
+        mock_issuer(
+            &vm,
+            first,
+            address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
+        );
+
+        // Neither the factory owner nor another issuer controls the collection
+        for caller in [owner, other_issuer] {
+            vm.set_sender(caller);
+            assert!(matches!(
+                factory.authorize_minter(first, minter),
+                Err(SBTFactoryError::Unauthorized(_))
+            ));
+            assert!(matches!(
+                factory.revoke_minter(first, minter),
+                Err(SBTFactoryError::Unauthorized(_))
+            ));
+        }
+        assert!(!factory.is_authorized_minter(first, minter));
+
+        vm.set_sender(other_issuer);
+        assert!(matches!(
+            factory.authorize_minter(unknown, minter),
+            Err(SBTFactoryError::CollectionNotRegistered(_))
+        ));
+
+        vm.set_sender(address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"));
+        assert!(matches!(
+            factory.authorize_minter(first, Address::ZERO),
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_registering_a_collection_does_not_grant_minter_control() {
+        let (vm, mut factory) = setup_factory();
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let squatter = address!("9965507D1a55bcC2695C58ba16FB37d819B0A4dc");
+        let minter = address!("15d34AAf54267DB7D7c367839AAf71A00a2C6A65");
+        let sbt_address = address!("976EA74026E726554dB657fA54763abd0C3a0aa9");
+
+        // The squatter lists a collection whose issuer is someone else
+        vm.set_sender(squatter);
+        assert!(factory
+            .register_sbt_collection(sbt_address, "Guild".to_string(), "GLD".to_string())
+            .is_ok());
+        assert_eq!(factory.get_collection_issuer(sbt_address), squatter);
+        mock_issuer(&vm, sbt_address, issuer);
+
+        assert!(matches!(
+            factory.authorize_minter(sbt_address, squatter),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+        assert!(!factory.is_authorized_minter(sbt_address, squatter));
+
+        // The collection's real issuer keeps control
+        vm.set_sender(issuer);
+        assert!(factory.authorize_minter(sbt_address, minter).is_ok());
+        assert!(factory.is_authorized_minter(sbt_address, minter));
+
+        // A collection that cannot report an issuer is controlled by nobody
+        mock_issuer(&vm, sbt_address, Address::ZERO);
+        assert!(!factory.is_authorized_minter(sbt_address, minter));
+        vm.set_sender(Address::ZERO);
+        assert!(matches!(
+            factory.authorize_minter(sbt_address, minter),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_minters_lapse_when_issuer_changes() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let new_issuer = address!("9965507D1a55bcC2695C58ba16FB37d819B0A4dc");
+        let minter = address!("15d34AAf54267DB7D7c367839AAf71A00a2C6A65");
+        let [first, _, _] = register_three(&vm, &mut factory);
+        mock_issuer(&vm, first, issuer);
+
+        assert!(factory.authorize_minter(first, minter).is_ok());
+        assert!(factory.is_authorized_minter(first, minter));
+
+        // e.g. after a lost-key recovery, the old issuer's grants stop counting
+        mock_issuer(&vm, first, new_issuer);
+        assert!(!factory.is_authorized_minter(first, minter));
+
+        // A deregistered collection has no minters at all
+        mock_issuer(&vm, first, issuer);
+        vm.set_sender(owner);
+        assert!(factory.deregister_sbt_collection(first).is_ok());
+        assert!(!factory.is_authorized_minter(first, minter));
+    }
+
+    // COLLECTION ADMIN TESTS
+
+    #[test]
+    fn test_initialize_collection_guards() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let stranger = address!("15d34AAf54267DB7D7c367839AAf71A00a2C6A65");
+        let unregistered = address!("9965507D1a55bcC2695C58ba16FB37d819B0A4dc");
+        let [first, _, _] = register_three(&vm, &mut factory);
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            factory.initialize_collection(unregistered, issuer),
+            Err(SBTFactoryError::CollectionNotRegistered(_))
+        ));
+        assert!(matches!(
+            factory.initialize_collection(first, Address::ZERO),
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
+
+        vm.set_sender(stranger);
+        assert!(matches!(
+            factory.initialize_collection(first, stranger),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+        // Having registered the collection grants nothing either
+        vm.set_sender(issuer);
+        assert!(matches!(
+            factory.initialize_collection(first, issuer),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+
+        // A collection that refuses, e.g. because it already has an issuer, fails the call
+        vm.mock_call(
+            first,
+            IEthosSBT::initializeCall { issuer }.abi_encode(),
+            Err(Vec::new()),
+        );
+        vm.set_sender(owner);
+        assert!(matches!(
+            factory.initialize_collection(first, issuer),
+            Err(SBTFactoryError::CollectionCallFailed(CollectionCallFailed { sbt_address }))
+                if sbt_address == first
+        ));
+    }
+
+    #[test]
+    fn test_recover_collection_issuer_guards() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let issuer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let new_issuer = address!("15d34AAf54267DB7D7c367839AAf71A00a2C6A65");
+        let unregistered = address!("9965507D1a55bcC2695C58ba16FB37d819B0A4dc");
+        let [first, _, _] = register_three(&vm, &mut factory);
+
+        // Only the owner; the collection's issuer is the one being replaced
+        vm.set_sender(issuer);
+        assert!(matches!(
+            factory.recover_collection_issuer(first, new_issuer),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            factory.recover_collection_issuer(unregistered, new_issuer),
+            Err(SBTFactoryError::CollectionNotRegistered(_))
+        ));
+        assert!(matches!(
+            factory.recover_collection_issuer(first, Address::ZERO),
+            Err(SBTFactoryError::AddressZeroNotAllowed(_))
+        ));
+
+        vm.mock_call(
+            first,
+            IEthosSBT::factorySetIssuerCall { new_issuer }.abi_encode(),
+            Err(Vec::new()),
+        );
+        assert!(matches!(
+            factory.recover_collection_issuer(first, new_issuer),
+            Err(SBTFactoryError::CollectionCallFailed(_))
+        ));
+    }
+
+    // RENOUNCE TESTS
+
+    #[test]
+    fn test_renounce_two_step() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(owner);
+        vm.set_block_timestamp(1_000);
+        assert!(factory.initiate_renounce().is_ok());
+        assert_eq!(
+            factory.get_renounce_deadline(),
+            U256::from(1_000 + RENOUNCE_WINDOW)
+        );
+        // Still the owner until confirmed
+        assert_eq!(factory.get_owner(), owner);
+
+        vm.set_block_timestamp(1_000 + RENOUNCE_WINDOW);
+        assert!(factory.confirm_renounce().is_ok());
+        assert_eq!(factory.get_owner(), Address::ZERO);
+        assert_eq!(factory.get_renounce_deadline(), U256::ZERO);
+
+        // Admin functions are gone for good
+        assert!(matches!(
+            factory.initiate_renounce(),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            factory.transfer_ownership(owner),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_confirm_renounce_without_initiate() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(owner);
+        assert!(matches!(
+            factory.confirm_renounce(),
+            Err(SBTFactoryError::RenounceNotInitiated(_))
+        ));
+        assert_eq!(factory.get_owner(), owner);
+    }
+
+    #[test]
+    fn test_confirm_renounce_after_window() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+
+        vm.set_sender(owner);
+        vm.set_block_timestamp(1_000);
+        assert!(factory.initiate_renounce().is_ok());
+
+        vm.set_block_timestamp(1_001 + RENOUNCE_WINDOW);
+        assert!(matches!(
+            factory.confirm_renounce(),
+            Err(SBTFactoryError::RenounceWindowExpired(_))
+        ));
+        assert_eq!(factory.get_owner(), owner);
+
+        // Initiating again opens a fresh window
+        assert!(factory.initiate_renounce().is_ok());
+        assert!(factory.confirm_renounce().is_ok());
+        assert_eq!(factory.get_owner(), Address::ZERO);
+    }
+
+    #[test]
+    fn test_renounce_access_and_handoff() {
+        let (vm, mut factory) = setup_factory();
+        let owner = address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720");
+        let alice = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+
+        vm.set_sender(alice);
+        assert!(matches!(
+            factory.initiate_renounce(),
+            Err(SBTFactoryError::Unauthorized(_))
+        ));
+
+        // A renounce started by the old owner does not carry over to the new one
+        vm.set_sender(owner);
+        assert!(factory.initiate_renounce().is_ok());
+        assert!(factory.transfer_ownership(alice).is_ok());
+        vm.set_sender(alice);
+        assert!(factory.accept_ownership().is_ok());
+        assert!(matches!(
+            factory.confirm_renounce(),
+            Err(SBTFactoryError::RenounceNotInitiated(_))
+        ));
+        assert_eq!(factory.get_owner(), alice);
+    }
 }